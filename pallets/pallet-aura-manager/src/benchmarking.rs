@@ -1,35 +1,76 @@
-//! Benchmarking setup for pallet-template
+//! Benchmarking setup for pallet-aura-manager
 #![cfg(feature = "runtime-benchmarks")]
 use super::*;
 
 #[allow(unused)]
-use crate::Pallet as Template;
+use crate::Pallet as AuraManager;
 use frame_benchmarking::v2::*;
-use frame_system::RawOrigin;
+use frame_support::{traits::Get, BoundedVec};
+
+fn validator<T: Config>(index: u32) -> T::ValidatorId {
+	let who: T::AccountId = account("validator", index, 0);
+	T::ValidatorId::try_from(who).unwrap_or_else(|_| panic!("validator id from account"))
+}
+
+fn validators<T: Config>(count: u32) -> Vec<T::ValidatorId> {
+	(0..count).map(validator::<T>).collect()
+}
+
+/// Fills `Validators` with `count` validators.
+fn set_validators<T: Config>(count: u32) -> Vec<T::ValidatorId> {
+	let validators = validators::<T>(count);
+	let list = BoundedVec::<T::ValidatorId, T::MaxAuthorities>::try_from(validators.clone())
+		.expect("count is within MaxAuthorities; qed");
+	Validators::<T>::put(list);
+	validators
+}
 
 #[benchmarks]
 mod benchmarks {
 	use super::*;
 
 	#[benchmark]
-	fn do_something() {
-		let value = 100u32.into();
-		let caller: T::AccountId = whitelisted_caller();
+	fn set_validator_list(v: Linear<1, { T::MaxAuthorities::get() }>) -> Result<(), BenchmarkError> {
+		let origin =
+			T::ValidatorManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let validators = validators::<T>(v);
+		let list = BoundedVec::<T::ValidatorId, T::MaxAuthorities>::try_from(validators.clone())
+			.expect("v is within MaxAuthorities; qed");
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, list);
+
+		assert_eq!(Validators::<T>::get().to_vec(), validators);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn add_validator_list(v: Linear<0, { T::MaxAuthorities::get() - 1 }>) -> Result<(), BenchmarkError> {
+		let origin =
+			T::ValidatorManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		set_validators::<T>(v);
+		let validator = validator::<T>(v);
+
 		#[extrinsic_call]
-		do_something(RawOrigin::Signed(caller), value);
+		_(origin as T::RuntimeOrigin, validator.clone());
 
-		assert_eq!(Something::<T>::get(), Some(value));
+		assert!(Validators::<T>::get().contains(&validator));
+		Ok(())
 	}
 
 	#[benchmark]
-	fn cause_error() {
-		Something::<T>::put(100u32);
-		let caller: T::AccountId = whitelisted_caller();
+	fn remove_validator_list(v: Linear<1, { T::MaxAuthorities::get() }>) -> Result<(), BenchmarkError> {
+		let origin =
+			T::ValidatorManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		// The last validator is the worst case for the lookup.
+		let validator = set_validators::<T>(v).pop().expect("v is at least 1; qed");
+
 		#[extrinsic_call]
-		cause_error(RawOrigin::Signed(caller));
+		_(origin as T::RuntimeOrigin, validator.clone());
 
-		assert_eq!(Something::<T>::get(), Some(101u32));
+		assert!(!Validators::<T>::get().contains(&validator));
+		Ok(())
 	}
 
-	impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
+	impl_benchmark_test_suite!(AuraManager, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -28,7 +28,6 @@ pub mod pallet {
 	use frame_support::sp_runtime::{
 		RuntimeAppPublic,
 	};
-	use bound_vec_helper::BoundVecHelper;
 
 
 	#[pallet::pallet]
@@ -56,6 +55,9 @@ pub mod pallet {
 
 		/// The maximum number of authorities that the pallet can hold.
 		type MaxAuthorities: Get<u32>;
+
+		/// The origin allowed to change the validator list.
+		type ValidatorManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 	}
 
 	#[pallet::storage]
@@ -110,31 +112,30 @@ pub mod pallet {
 	impl<T: Config> Pallet<T> {
 
 		#[pallet::call_index(0)]
-		#[pallet::weight(1)]
-		pub fn set_validator_list(origin: OriginFor<T>, validators: Vec<T::ValidatorId> ) -> DispatchResult {
-			// Check that the extrinsic was signed and get the signer.
-			// This function will return an error if the extrinsic is not signed.
+		#[pallet::weight(T::WeightInfo::set_validator_list(validators.len() as u32))]
+		pub fn set_validator_list(
+			origin: OriginFor<T>,
+			validators: BoundedVec<T::ValidatorId, T::MaxAuthorities>,
+		) -> DispatchResult {
+			// Only the validator manager may change the validator list.
 			// https://docs.substrate.io/main-docs/build/origins/
-			ensure_root(origin)?;
-
-			let list = BoundedVec::<T::ValidatorId, T::MaxAuthorities>::create_on_vec(validators.clone());
+			T::ValidatorManagerOrigin::ensure_origin(origin)?;
 
-			// Update storage.
-			<Validators<T>>::put(list );
+			// Update storage, lists longer than `MaxAuthorities` do not decode.
+			<Validators<T>>::put(&validators);
 
 			// Emit an event.
-			Self::deposit_event(Event::SetAuthorities { validators: validators.to_vec() });
+			Self::deposit_event(Event::SetAuthorities { validators: validators.into_inner() });
 			// Return a successful DispatchResultWithPostInfo
 			Ok(())
 		}
 
 		#[pallet::call_index(1)]
-		#[pallet::weight(1)]
+		#[pallet::weight(T::WeightInfo::add_validator_list(T::MaxAuthorities::get()))]
 		pub fn add_validator_list(origin: OriginFor<T>, validator: T::ValidatorId ) -> DispatchResult {
-			// Check that the extrinsic was signed and get the signer.
-			// This function will return an error if the extrinsic is not signed.
+			// Only the validator manager may change the validator list.
 			// https://docs.substrate.io/main-docs/build/origins/
-			ensure_root(origin)?;
+			T::ValidatorManagerOrigin::ensure_origin(origin)?;
 			// get old list
 			let mut list = <Validators<T>>::get();
 			list.try_push(validator.clone()).map_err(|_| Error::<T>::TooManyAuthorities)?;
@@ -149,12 +150,11 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(2)]
-		#[pallet::weight(1)]
+		#[pallet::weight(T::WeightInfo::remove_validator_list(T::MaxAuthorities::get()))]
 		pub fn remove_validator_list(origin: OriginFor<T>, validator: T::ValidatorId ) -> DispatchResult {
-			// Check that the extrinsic was signed and get the signer.
-			// This function will return an error if the extrinsic is not signed.
+			// Only the validator manager may change the validator list.
 			// https://docs.substrate.io/main-docs/build/origins/
-			ensure_root(origin)?;
+			T::ValidatorManagerOrigin::ensure_origin(origin)?;
 
 			// Get old list
 			let mut list = <Validators<T>>::get();
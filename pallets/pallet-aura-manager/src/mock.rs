@@ -49,6 +49,7 @@ impl pallet_aura_manager::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type ValidatorId = u64;
 	type MaxAuthorities = ConstU32<10>;
+	type ValidatorManagerOrigin = frame_system::EnsureRoot<u64>;
 	type WeightInfo = ();
 }
 
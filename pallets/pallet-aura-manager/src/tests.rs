@@ -1,5 +1,5 @@
 use crate::{mock::*, Error, Event};
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, bounded_vec};
 use sp_consensus_aura::sr25519::AuthorityId;
 use sp_core::ByteArray;
 // use sp_api::RuntimeApiInfo;
@@ -21,7 +21,7 @@ fn it_works_for_set_validator_list() {
 		// Go past genesis block so events get deposited
 		System::set_block_number(1);
 		// Dispatch a signed extrinsic.
-		assert_ok!(AuraManager::set_validator_list(RuntimeOrigin::root(), vec![
+		assert_ok!(AuraManager::set_validator_list(RuntimeOrigin::root(), bounded_vec![
 			1,2,3,4,5
 		]));
 		// Check storage
@@ -44,3 +44,15 @@ fn it_works_for_set_validator_list() {
 	});
 }
 
+
+#[test]
+fn correct_error_for_validator_manager_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AuraManager::set_validator_list(RuntimeOrigin::signed(1), bounded_vec![1, 2, 3]),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(AuraManager::add_validator_list(RuntimeOrigin::signed(1), 1), sp_runtime::DispatchError::BadOrigin);
+		assert_noop!(AuraManager::remove_validator_list(RuntimeOrigin::signed(1), 1), sp_runtime::DispatchError::BadOrigin);
+	});
+}
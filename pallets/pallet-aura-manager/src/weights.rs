@@ -1,29 +1,12 @@
-
-//! Autogenerated weights for pallet_template
+//! Weights for pallet_aura_manager.
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2023-04-06, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `Alexs-MacBook-Pro-2.local`, CPU: `<UNKNOWN>`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
-
-// Executed Command:
-// ../../target/release/node-eternal-civilization
-// benchmark
-// pallet
-// --chain
-// dev
-// --pallet
-// pallet_template
-// --extrinsic
-// *
-// --steps=50
-// --repeat=20
-// --wasm-execution=compiled
-// --output
-// pallets/template/src/weights.rs
-// --template
-// ../../.maintain/frame-weight-template.hbs
+//! PLACEHOLDER WEIGHTS, NOT BENCHMARK OUTPUT. The storage accesses are taken from the code,
+//! the execution times and proof sizes are rounded up guesses. Replace this file with the
+//! output of the benchmark CLI on reference hardware before production use:
+//!
+//! ./target/release/node-eternal-civilization benchmark pallet --chain dev --pallet pallet_aura_manager \
+//!     --extrinsic '*' --steps 50 --repeat 20 --wasm-execution compiled \
+//!     --output pallets/pallet-aura-manager/src/weights.rs --template ../../.maintain/frame-weight-template.hbs
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -32,33 +15,36 @@
 use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
 use core::marker::PhantomData;
 
-/// Weight functions needed for pallet_template.
+/// Weight functions needed for pallet_aura_manager.
 pub trait WeightInfo {
-	fn do_something() -> Weight;
-	fn cause_error() -> Weight;
+	fn set_validator_list(v: u32) -> Weight;
+	fn add_validator_list(v: u32) -> Weight;
+	fn remove_validator_list(v: u32) -> Weight;
 }
 
-/// Weights for pallet_template using the Substrate node and recommended hardware.
+/// Weights for pallet_aura_manager using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-	/// Storage: TemplateModule Something (r:0 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn do_something() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 8_000_000 picoseconds.
+	/// Storage: AuraManager Validators (r:0 w:1)
+	/// The range of component `v` is `[1, MaxAuthorities]`.
+	fn set_validator_list(v: u32) -> Weight {
 		Weight::from_parts(9_000_000, 0)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(v.into()))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-	/// Storage: TemplateModule Something (r:1 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn cause_error() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
+	/// Storage: AuraManager Validators (r:1 w:1)
+	/// The range of component `v` is `[0, MaxAuthorities - 1]`.
+	fn add_validator_list(v: u32) -> Weight {
+		Weight::from_parts(20_000_000, 2000)
+			.saturating_add(Weight::from_parts(70_000, 40).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: AuraManager Validators (r:1 w:1)
+	/// The range of component `v` is `[1, MaxAuthorities]`.
+	fn remove_validator_list(v: u32) -> Weight {
+		Weight::from_parts(20_000_000, 2000)
+			.saturating_add(Weight::from_parts(100_000, 40).saturating_mul(v.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -66,24 +52,26 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
-	/// Storage: TemplateModule Something (r:0 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn do_something() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 8_000_000 picoseconds.
+	/// Storage: AuraManager Validators (r:0 w:1)
+	/// The range of component `v` is `[1, MaxAuthorities]`.
+	fn set_validator_list(v: u32) -> Weight {
 		Weight::from_parts(9_000_000, 0)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: AuraManager Validators (r:1 w:1)
+	/// The range of component `v` is `[0, MaxAuthorities - 1]`.
+	fn add_validator_list(v: u32) -> Weight {
+		Weight::from_parts(20_000_000, 2000)
+			.saturating_add(Weight::from_parts(70_000, 40).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-	/// Storage: TemplateModule Something (r:1 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn cause_error() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
+	/// Storage: AuraManager Validators (r:1 w:1)
+	/// The range of component `v` is `[1, MaxAuthorities]`.
+	fn remove_validator_list(v: u32) -> Weight {
+		Weight::from_parts(20_000_000, 2000)
+			.saturating_add(Weight::from_parts(100_000, 40).saturating_mul(v.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
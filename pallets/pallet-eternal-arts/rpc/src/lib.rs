@@ -0,0 +1,159 @@
+//! JSON-RPC endpoints for pallet-eternal-arts, served from `EternalArtsApi`.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use serde::{de::DeserializeOwned, Serialize};
+use jsonrpsee::{
+	core::{Error as JsonRpseeError, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_eternal_arts_runtime_api::{
+	CollectionInfo, EternalArtsApi as EternalArtsRuntimeApi, RelationId, TypeContentHash, TypeCount, TypeRequestId,
+	TypeSid,
+};
+
+/// The largest page `eternalArts_holdersOf` returns.
+pub const MAX_HOLDERS_PAGE: u32 = 1000;
+
+/// Error code of a failed runtime call.
+const RUNTIME_ERROR: i32 = 1;
+
+#[rpc(client, server)]
+pub trait EternalArtsApi<BlockHash, AccountId, BlockNumber> {
+	#[method(name = "eternalArts_collectionInfo")]
+	fn collection_info(&self, s_id: TypeSid, at: Option<BlockHash>) -> RpcResult<Option<CollectionInfo<AccountId>>>;
+
+	#[method(name = "eternalArts_holdingsOf")]
+	fn holdings_of(&self, b_id: RelationId, at: Option<BlockHash>) -> RpcResult<Vec<(TypeSid, TypeCount)>>;
+
+	#[method(name = "eternalArts_holdersOf")]
+	fn holders_of(
+		&self,
+		s_id: TypeSid,
+		start_after: Option<RelationId>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(RelationId, TypeCount)>>;
+
+	#[method(name = "eternalArts_totalSupply")]
+	fn total_supply(&self, s_id: TypeSid, at: Option<BlockHash>) -> RpcResult<TypeCount>;
+
+	#[method(name = "eternalArts_isRequestProcessed")]
+	fn is_request_processed(&self, who: AccountId, request_id: TypeRequestId, at: Option<BlockHash>) -> RpcResult<bool>;
+
+	#[method(name = "eternalArts_administratorExpiries")]
+	fn administrator_expiries(&self, until: BlockNumber, at: Option<BlockHash>) -> RpcResult<Vec<(AccountId, BlockNumber)>>;
+
+	#[method(name = "eternalArts_collectionOfContentHash")]
+	fn collection_of_content_hash(&self, content_hash: TypeContentHash, at: Option<BlockHash>) -> RpcResult<Option<TypeSid>>;
+
+	#[method(name = "eternalArts_verifyContentHash")]
+	fn verify_content_hash(&self, s_id: TypeSid, content_hash: TypeContentHash, at: Option<BlockHash>) -> RpcResult<bool>;
+}
+
+/// Serves `EternalArtsApiServer` from a client with the `EternalArtsApi` runtime API.
+pub struct EternalArts<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> EternalArts<C, Block> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block, AccountId, BlockNumber> EternalArtsApiServer<<Block as BlockT>::Hash, AccountId, BlockNumber>
+	for EternalArts<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: EternalArtsRuntimeApi<Block, AccountId, BlockNumber>,
+	AccountId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	BlockNumber: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+	fn collection_info(
+		&self,
+		s_id: TypeSid,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<CollectionInfo<AccountId>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().collection_info(at, s_id).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn holdings_of(
+		&self,
+		b_id: RelationId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(TypeSid, TypeCount)>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().holdings_of(at, b_id).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn holders_of(
+		&self,
+		s_id: TypeSid,
+		start_after: Option<RelationId>,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(RelationId, TypeCount)>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.holders_of(at, s_id, start_after, limit.min(MAX_HOLDERS_PAGE))
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn total_supply(&self, s_id: TypeSid, at: Option<<Block as BlockT>::Hash>) -> RpcResult<TypeCount> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().total_supply(at, s_id).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn is_request_processed(
+		&self,
+		who: AccountId,
+		request_id: TypeRequestId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<bool> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().is_request_processed(at, who, request_id).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn administrator_expiries(
+		&self,
+		until: BlockNumber,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(AccountId, BlockNumber)>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().administrator_expiries(at, until).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn collection_of_content_hash(
+		&self,
+		content_hash: TypeContentHash,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<TypeSid>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().collection_of_content_hash(at, content_hash).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn verify_content_hash(
+		&self,
+		s_id: TypeSid,
+		content_hash: TypeContentHash,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<bool> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().verify_content_hash(at, s_id, content_hash).map_err(runtime_error_into_rpc_err)
+	}
+}
+
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> JsonRpseeError {
+	CallError::Custom(ErrorObject::owned(RUNTIME_ERROR, "Runtime error", Some(format!("{:?}", err)))).into()
+}
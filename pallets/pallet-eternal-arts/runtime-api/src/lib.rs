@@ -0,0 +1,42 @@
+//! Runtime API definition for pallet-eternal-arts.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use pallet_eternal_arts::{CollectionInfo, TypeContentHash, TypeCount, TypeRequestId, TypeSid};
+
+/// A relation id, it encodes like the `BidOf` of the runtime, bounded by its `MaxBidLength`.
+pub type RelationId = Vec<u8>;
+
+sp_api::decl_runtime_apis! {
+	pub trait EternalArtsApi<AccountId, BlockNumber>
+	where
+		AccountId: Codec,
+		BlockNumber: Codec,
+	{
+		/// The data, status, owner and supply of a collection.
+		fn collection_info(s_id: TypeSid) -> Option<CollectionInfo<AccountId>>;
+
+		/// The collections held by a relation id, with their count.
+		fn holdings_of(b_id: RelationId) -> Vec<(TypeSid, TypeCount)>;
+
+		/// At most `limit` holders of a collection, starting after `start_after`.
+		fn holders_of(s_id: TypeSid, start_after: Option<RelationId>, limit: u32) -> Vec<(RelationId, TypeCount)>;
+
+		/// The number of units issued for a collection.
+		fn total_supply(s_id: TypeSid) -> TypeCount;
+
+		/// Whether a mint batch of `who` with `request_id` was processed and has not expired yet.
+		fn is_request_processed(who: AccountId, request_id: TypeRequestId) -> bool;
+
+		/// The administrators expiring up to `until`, earliest first.
+		fn administrator_expiries(until: BlockNumber) -> Vec<(AccountId, BlockNumber)>;
+
+		/// The collection committed to the artwork file with `content_hash`.
+		fn collection_of_content_hash(content_hash: TypeContentHash) -> Option<TypeSid>;
+
+		/// Whether a collection is committed to the artwork file with `content_hash`.
+		fn verify_content_hash(s_id: TypeSid, content_hash: TypeContentHash) -> bool;
+	}
+}
@@ -1,35 +1,453 @@
-//! Benchmarking setup for pallet-template
+//! Benchmarking setup for pallet-eternal-arts
 #![cfg(feature = "runtime-benchmarks")]
 use super::*;
 
 #[allow(unused)]
-use crate::Pallet as Template;
+use crate::Pallet as EternalArts;
 use frame_benchmarking::v2::*;
+use frame_support::{sp_runtime::traits::Bounded, traits::Get, BoundedVec};
 use frame_system::RawOrigin;
+use sp_std::{vec, vec::Vec};
 
-#[benchmarks]
+/// The whitelisted caller, holding the given global roles.
+fn administrator<T: Config<I>, I: 'static>(roles: &[AdminRole]) -> T::AccountId {
+	let caller: T::AccountId = whitelisted_caller();
+	let mut admin_roles = AdminRoles::default();
+	for role in roles.iter() {
+		admin_roles.insert(role);
+	}
+	Administrators::<T, I>::insert(&caller, admin_roles);
+	caller
+}
+
+/// An account acting through `role` scoped to the collections, neither their owner nor a global
+/// holder of `role`. It holds another, expiring global role, so that every check of
+/// `is_collection_administrator` reads storage.
+fn collection_administrator<T: Config<I>, I: 'static>(
+	s_ids: impl IntoIterator<Item = TypeSid>,
+	role: AdminRole,
+) -> T::AccountId {
+	let who: T::AccountId = account("collection_administrator", 0, 0);
+	Administrators::<T, I>::insert(&who, AdminRoles(AdminRole::Locker.flag()));
+	AdministratorExpiries::<T, I>::insert(&who, BlockNumberFor::<T>::max_value());
+	for s_id in s_ids {
+		CollectionAdministrators::<T, I>::mutate(s_id, &who, |roles| roles.insert(&role));
+	}
+	who
+}
+
+fn bounded_data<S: Get<u32>>(len: u32) -> BoundedVec<u8, S> {
+	BoundedVec::try_from(vec![b'a'; len as usize]).expect("len is within the bound; qed")
+}
+
+/// A uri of `len` bytes, an `ipfs://` uri when allowed since its CID has to be decoded.
+fn collection_uri<T: Config<I>, I: 'static>(len: u32) -> UriOf<T, I> {
+	let schemes = T::UriSchemes::get();
+	let mut uri = if schemes.contains(&uri::IPFS_SCHEME) {
+		[uri::IPFS_SCHEME, &b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG/"[..]].concat()
+	} else {
+		schemes[0].to_vec()
+	};
+	uri.resize(len as usize, b'a');
+	UriOf::<T, I>::try_from(uri).expect("len is within the bound; qed")
+}
+
+fn relation_id<T: Config<I>, I: 'static>(index: u32) -> BidOf<T, I> {
+	BidOf::<T, I>::try_from(index.to_le_bytes().to_vec()).expect("four bytes fit in a relation id; qed")
+}
+
+/// Creates a collection owned by `owner` with the longest name and uri.
+fn create_collection<T: Config<I>, I: 'static>(owner: &T::AccountId) -> TypeSid {
+	let s_id = NextCollectionId::<T, I>::get();
+	assert!(EternalArts::<T, I>::create_art_collection(
+		RawOrigin::Signed(owner.clone()).into(),
+		bounded_data(T::MaxNameLength::get()),
+		collection_uri::<T, I>(T::MaxUriLength::get()),
+	)
+	.is_ok());
+	s_id
+}
+
+/// One unit of a distinct collection for a distinct relation id, for each of the `b` items.
+fn mint_items<T: Config<I>, I: 'static>(owner: &T::AccountId, b: u32) -> MintItemsOf<T, I> {
+	let items: Vec<MintItemOf<T, I>> = (0..b)
+		.map(|i| MintItem { b_id: relation_id::<T, I>(i), s_id: create_collection::<T, I>(owner), count: 1 })
+		.collect();
+	MintItemsOf::<T, I>::try_from(items).expect("b is within MaxBatch; qed")
+}
+
+/// The longest request id, distinct per `index`.
+fn request_id(index: u32) -> TypeRequestId {
+	let mut request_id = vec![b'r'; TypeRequestIdLength::get() as usize];
+	request_id[..4].copy_from_slice(&index.to_le_bytes());
+	TypeRequestId::try_from(request_id).expect("the length is the bound; qed")
+}
+
+#[instance_benchmarks]
 mod benchmarks {
 	use super::*;
 
 	#[benchmark]
-	fn do_something() {
-		let value = 100u32.into();
-		let caller: T::AccountId = whitelisted_caller();
+	fn create_art_collection(
+		n: Linear<1, { T::MaxNameLength::get() }>,
+		u: Linear<54, { T::MaxUriLength::get() }>,
+	) {
+		let caller = administrator::<T, I>(&[AdminRole::Creator]);
+		let name = bounded_data(n);
+		let uri = collection_uri::<T, I>(u);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), name, uri);
+
+		assert_eq!(CollectionOwner::<T, I>::get(0), Some(caller));
+	}
+
+	#[benchmark]
+	fn update_art_collection(
+		n: Linear<1, { T::MaxNameLength::get() }>,
+		u: Linear<54, { T::MaxUriLength::get() }>,
+	) {
+		let owner = administrator::<T, I>(&[AdminRole::Creator]);
+		let s_id = create_collection::<T, I>(&owner);
+		let caller = collection_administrator::<T, I>([s_id], AdminRole::MetadataEditor);
+		let name = bounded_data(n);
+		let uri = collection_uri::<T, I>(u);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), s_id, name.clone(), uri.clone());
+
+		assert_eq!(ArtCollection::<T, I>::get(s_id).map(|data| (data.name, data.uri)), Some((name, uri)));
+	}
+
+	#[benchmark]
+	fn issue_art_ownership(b: Linear<1, { T::MaxBatch::get() }>) {
+		let owner = administrator::<T, I>(&[AdminRole::Creator]);
+		let items = mint_items::<T, I>(&owner, b);
+		let caller = collection_administrator::<T, I>(items.iter().map(|item| item.s_id), AdminRole::Minter);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), items.clone(), Some(request_id(0)));
+
+		for item in items.iter() {
+			assert_eq!(NftBindInfos::<T, I>::get(&item.b_id, item.s_id), 1);
+		}
+		assert!(ProcessedRequests::<T, I>::contains_key(&caller, request_id(0)));
+	}
+
+	#[benchmark]
+	fn issue_art_ownership_best_effort(b: Linear<1, { T::MaxBatch::get() }>) {
+		let owner = administrator::<T, I>(&[AdminRole::Creator]);
+		let items = mint_items::<T, I>(&owner, b);
+		let caller = collection_administrator::<T, I>(items.iter().map(|item| item.s_id), AdminRole::Minter);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), items.clone(), Some(request_id(0)));
+
+		for item in items.iter() {
+			assert_eq!(NftBindInfos::<T, I>::get(&item.b_id, item.s_id), 1);
+		}
+		assert!(ProcessedRequests::<T, I>::contains_key(&caller, request_id(0)));
+	}
+
+	#[benchmark]
+	fn update_administrator_list(
+		c: Linear<0, { T::MaxAdministrators::get() }>,
+		a: Linear<1, { T::MaxAdministrators::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		// `c` expiring administrators to clear, the call is charged for `MaxAdministrators`.
+		let _ = Administrators::<T, I>::clear(u32::MAX, None);
+		let expires_at = BlockNumberFor::<T>::max_value();
+		for i in 0..c {
+			let who: T::AccountId = account("cleared", i, 0);
+			Administrators::<T, I>::insert(&who, AdminRoles(AdminRole::Locker.flag()));
+			AdministratorExpiries::<T, I>::insert(&who, expires_at);
+			AdministratorsByExpiry::<T, I>::insert(expires_at, &who, ());
+		}
+		if c > 0 {
+			NextAdministratorExpiry::<T, I>::put(expires_at);
+		}
+		let administrator_list: Vec<(T::AccountId, AdminRole)> =
+			(0..a).map(|i| (account("administrator", i, 0), AdminRole::Minter)).collect();
+		let administrator_list =
+			AdministratorListOf::<T, I>::try_from(administrator_list).expect("a is within MaxAdministrators; qed");
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, administrator_list);
+
+		assert!(Administrators::<T, I>::get(account::<T::AccountId>("administrator", 0, 0)).contains(&AdminRole::Minter));
+		assert_eq!(Administrators::<T, I>::count(), a);
+		assert_eq!(AdministratorExpiries::<T, I>::iter_keys().count(), 0);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_collection_status() {
+		let caller = administrator::<T, I>(&[AdminRole::Creator]);
+		let s_id = create_collection::<T, I>(&caller);
+
 		#[extrinsic_call]
-		do_something(RawOrigin::Signed(caller), value);
+		_(RawOrigin::Signed(caller), s_id, true);
 
-		assert_eq!(Something::<T>::get(), Some(value));
+		assert!(ArtStatus::<T, I>::get(s_id).locked);
 	}
 
 	#[benchmark]
-	fn cause_error() {
-		Something::<T>::put(100u32);
-		let caller: T::AccountId = whitelisted_caller();
+	fn set_collection_limit() {
+		let caller = administrator::<T, I>(&[AdminRole::Creator]);
+		let s_id = create_collection::<T, I>(&caller);
+
 		#[extrinsic_call]
-		cause_error(RawOrigin::Signed(caller));
+		_(RawOrigin::Signed(caller), s_id, Some(100));
+
+		assert_eq!(ArtStatus::<T, I>::get(s_id).limit, Some(100));
+	}
+
+	#[benchmark]
+	fn set_collection_type() {
+		let caller = administrator::<T, I>(&[AdminRole::Creator]);
+		let s_id = create_collection::<T, I>(&caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), s_id, ArtType::LimitedEdition);
+
+		assert_eq!(ArtStatus::<T, I>::get(s_id).art_type, ArtType::LimitedEdition);
+	}
+
+	#[benchmark]
+	fn transfer_art_ownership() {
+		let owner = administrator::<T, I>(&[AdminRole::Creator]);
+		let s_id = create_collection::<T, I>(&owner);
+		let caller = collection_administrator::<T, I>([s_id], AdminRole::Minter);
+		let from_bid = relation_id::<T, I>(0);
+		let to_bid = relation_id::<T, I>(1);
+		let items = MintItemsOf::<T, I>::try_from(vec![MintItem { b_id: from_bid.clone(), s_id, count: 2 }])
+			.expect("MaxBatch allows at least one item; qed");
+		assert!(EternalArts::<T, I>::issue_art_ownership(RawOrigin::Signed(caller.clone()).into(), items, None).is_ok());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), from_bid.clone(), to_bid.clone(), s_id, 1);
+
+		assert_eq!(NftBindInfos::<T, I>::get(&to_bid, s_id), 1);
+	}
+
+	#[benchmark]
+	fn revoke_art_ownership(b: Linear<1, { T::MaxBatch::get() }>) {
+		let owner = administrator::<T, I>(&[AdminRole::Creator]);
+		let items = mint_items::<T, I>(&owner, b);
+		let caller = collection_administrator::<T, I>(items.iter().map(|item| item.s_id), AdminRole::Minter);
+		assert!(EternalArts::<T, I>::issue_art_ownership(RawOrigin::Signed(caller.clone()).into(), items.clone(), None).is_ok());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), items.clone(), RevokeReason::Mistake);
+
+		for item in items.iter() {
+			assert_eq!(NftCount::<T, I>::get(item.s_id), 0);
+		}
+	}
+
+	#[benchmark]
+	fn grant_role() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let who: T::AccountId = account("administrator", 0, 0);
+		// Replacing an expired entry that was not pruned yet is the worst case.
+		let expires_at = frame_system::Pallet::<T>::block_number();
+		Administrators::<T, I>::insert(&who, AdminRoles(AdminRole::Locker.flag()));
+		AdministratorExpiries::<T, I>::insert(&who, expires_at);
+		AdministratorsByExpiry::<T, I>::insert(expires_at, &who, ());
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, who.clone(), AdminRole::Minter);
+
+		assert_eq!(Administrators::<T, I>::get(&who), AdminRoles(AdminRole::Minter.flag()));
+		assert!(!AdministratorExpiries::<T, I>::contains_key(&who));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn revoke_role() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let who = administrator::<T, I>(&[AdminRole::Minter]);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, who.clone(), AdminRole::Minter);
+
+		assert!(!Administrators::<T, I>::contains_key(&who));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn grant_collection_role() {
+		let caller = administrator::<T, I>(&[AdminRole::Creator]);
+		let s_id = create_collection::<T, I>(&caller);
+		let who: T::AccountId = account("minter", 0, 0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), s_id, who.clone(), AdminRole::Minter);
+
+		assert!(CollectionAdministrators::<T, I>::get(s_id, &who).contains(&AdminRole::Minter));
+	}
+
+	#[benchmark]
+	fn revoke_collection_role() {
+		let caller = administrator::<T, I>(&[AdminRole::Creator]);
+		let s_id = create_collection::<T, I>(&caller);
+		let who: T::AccountId = account("minter", 0, 0);
+		CollectionAdministrators::<T, I>::insert(s_id, &who, AdminRoles(AdminRole::Minter.flag()));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), s_id, who.clone(), AdminRole::Minter);
+
+		assert!(!CollectionAdministrators::<T, I>::contains_key(s_id, &who));
+	}
+
+	#[benchmark]
+	fn force_create_art_collection(
+		n: Linear<1, { T::MaxNameLength::get() }>,
+		u: Linear<54, { T::MaxUriLength::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let owner: T::AccountId = account("owner", 0, 0);
+		let name = bounded_data(n);
+		let uri = collection_uri::<T, I>(u);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, 1_000, owner.clone(), name, uri);
+
+		assert_eq!(CollectionOwner::<T, I>::get(1_000), Some(owner));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn add_administrator() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let who: T::AccountId = account("administrator", 0, 0);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, who.clone(), AdminRole::Minter);
+
+		assert!(Administrators::<T, I>::get(&who).contains(&AdminRole::Minter));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_administrator() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let who = administrator::<T, I>(&[AdminRole::Creator, AdminRole::Minter]);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, who.clone());
+
+		assert!(!Administrators::<T, I>::contains_key(&who));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_administrator_expiry() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let who = administrator::<T, I>(&[AdminRole::Minter]);
+		// Replacing an existing expiry is the worst case.
+		let now = frame_system::Pallet::<T>::block_number();
+		AdministratorExpiries::<T, I>::insert(&who, now + 10u32.into());
+		AdministratorsByExpiry::<T, I>::insert(now + 10u32.into(), &who, ());
+		let expires_at = now + 20u32.into();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, who.clone(), Some(expires_at));
+
+		assert_eq!(AdministratorExpiries::<T, I>::get(&who), Some(expires_at));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_attribute(
+		k: Linear<1, { T::MaxAttributeKeyLength::get() }>,
+		v: Linear<0, { T::MaxAttributeValueLength::get() }>,
+	) {
+		let owner = administrator::<T, I>(&[AdminRole::Creator]);
+		let s_id = create_collection::<T, I>(&owner);
+		let caller = collection_administrator::<T, I>([s_id], AdminRole::MetadataEditor);
+		T::Currency::make_free_balance_be(&owner, BalanceOf::<T, I>::max_value() / 4u32.into());
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T, I>::max_value() / 4u32.into());
+		let key: AttributeKeyOf<T, I> = bounded_data(k);
+		let value = bounded_data(v);
+		// Replacing the value of another depositor is the worst case.
+		assert!(EternalArts::<T, I>::set_attribute(
+			RawOrigin::Signed(owner).into(),
+			s_id,
+			key.clone(),
+			bounded_data(T::MaxAttributeValueLength::get()),
+		)
+		.is_ok());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), s_id, key.clone(), value);
+
+		assert!(CollectionAttributes::<T, I>::contains_key(s_id, key));
+	}
+
+	#[benchmark]
+	fn clear_attribute() {
+		let owner = administrator::<T, I>(&[AdminRole::Creator]);
+		let s_id = create_collection::<T, I>(&owner);
+		let caller = collection_administrator::<T, I>([s_id], AdminRole::MetadataEditor);
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T, I>::max_value() / 2u32.into());
+		let key: AttributeKeyOf<T, I> = bounded_data(T::MaxAttributeKeyLength::get());
+		assert!(EternalArts::<T, I>::set_attribute(
+			RawOrigin::Signed(caller.clone()).into(),
+			s_id,
+			key.clone(),
+			bounded_data(T::MaxAttributeValueLength::get()),
+		)
+		.is_ok());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), s_id, key.clone());
+
+		assert!(!CollectionAttributes::<T, I>::contains_key(s_id, key));
+	}
+
+	#[benchmark]
+	fn register_content_hash() {
+		let owner = administrator::<T, I>(&[AdminRole::Creator]);
+		let s_id = create_collection::<T, I>(&owner);
+		let caller = collection_administrator::<T, I>([s_id], AdminRole::MetadataEditor);
+		let content_hash = [1u8; 32];
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), s_id, content_hash);
+
+		assert_eq!(ContentHashes::<T, I>::get(content_hash), Some(s_id));
+	}
+
+	/// One administrator pruned by `on_idle`, charged per entry.
+	#[benchmark]
+	fn prune_expired_administrator() {
+		// Not the whitelisted caller, so that reading its roles is counted.
+		let who: T::AccountId = account("expired", 0, 0);
+		Administrators::<T, I>::insert(&who, AdminRoles(AdminRole::Minter.flag()));
+		let expires_at = frame_system::Pallet::<T>::block_number();
+		AdministratorExpiries::<T, I>::insert(&who, expires_at);
+		AdministratorsByExpiry::<T, I>::insert(expires_at, &who, ());
+
+		#[block]
+		{
+			let (expired, ()) = AdministratorsByExpiry::<T, I>::drain_prefix(expires_at)
+				.next()
+				.expect("the administrator expires at this block; qed");
+			EternalArts::<T, I>::remove_expired_administrator(expired);
+		}
 
-		assert_eq!(Something::<T>::get(), Some(101u32));
+		assert!(!Administrators::<T, I>::contains_key(&who));
+		assert!(!AdministratorExpiries::<T, I>::contains_key(&who));
 	}
 
-	impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
+	impl_benchmark_test_suite!(EternalArts, crate::mock::new_test_ext(), crate::mock::Test);
 }
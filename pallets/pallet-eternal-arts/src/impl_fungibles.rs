@@ -0,0 +1,167 @@
+//! Implementations of the `fungibles` traits for pallet-eternal-arts.
+//!
+//! Every collection is an asset identified by its `s_id`, the balance of a relation id is the
+//! number of units it holds in `NftBindInfos`. Deposits follow the supply rules of the collection
+//! and soulbound units can only be minted and burned, never transferred.
+use super::*;
+use frame_support::dispatch::DispatchError;
+use frame_support::sp_runtime::traits::Zero;
+use frame_support::traits::tokens::{
+	fungibles::{Dust, Inspect, Mutate, Unbalanced},
+	DepositConsequence, Fortitude, Preservation, Provenance, WithdrawConsequence,
+};
+
+impl<T: Config<I>, I: 'static> Inspect<BidOf<T, I>> for Pallet<T, I> {
+	type AssetId = TypeSid;
+	type Balance = TypeCount;
+
+	fn total_issuance(asset: Self::AssetId) -> Self::Balance {
+		NftCount::<T, I>::get(asset)
+	}
+
+	/// Holdings are removed once they reach zero, there is no existential balance.
+	fn minimum_balance(_asset: Self::AssetId) -> Self::Balance {
+		Zero::zero()
+	}
+
+	fn total_balance(asset: Self::AssetId, who: &BidOf<T, I>) -> Self::Balance {
+		NftBindInfos::<T, I>::get(who, asset)
+	}
+
+	fn balance(asset: Self::AssetId, who: &BidOf<T, I>) -> Self::Balance {
+		NftBindInfos::<T, I>::get(who, asset)
+	}
+
+	/// Nothing can be taken out of a locked collection unless forced.
+	fn reducible_balance(
+		asset: Self::AssetId,
+		who: &BidOf<T, I>,
+		_preservation: Preservation,
+		force: Fortitude,
+	) -> Self::Balance {
+		if force == Fortitude::Polite && ArtStatus::<T, I>::get(asset).locked {
+			return Zero::zero();
+		}
+		NftBindInfos::<T, I>::get(who, asset)
+	}
+
+	fn can_deposit(
+		asset: Self::AssetId,
+		who: &BidOf<T, I>,
+		amount: Self::Balance,
+		provenance: Provenance,
+	) -> DepositConsequence {
+		if !ArtCollection::<T, I>::contains_key(asset) {
+			return DepositConsequence::UnknownAsset;
+		}
+		let art_status = ArtStatus::<T, I>::get(asset);
+		if art_status.locked {
+			return DepositConsequence::Blocked;
+		}
+		match provenance {
+			Provenance::Minted => {
+				if art_status.art_type == ArtType::LimitedEdition && art_status.limit.is_none() {
+					return DepositConsequence::Blocked;
+				}
+				match NftCount::<T, I>::get(asset).checked_add(amount) {
+					Some(new_count) if art_status.max_supply().map_or(true, |limit| new_count <= limit) => {},
+					_ => return DepositConsequence::Overflow,
+				}
+			},
+			// Existing units only arrive through transfers.
+			Provenance::Extant => {
+				if !art_status.is_transferable() {
+					return DepositConsequence::Blocked;
+				}
+			},
+		}
+		if NftBindInfos::<T, I>::get(who, asset).checked_add(amount).is_none() {
+			return DepositConsequence::Overflow;
+		}
+		DepositConsequence::Success
+	}
+
+	fn can_withdraw(
+		asset: Self::AssetId,
+		who: &BidOf<T, I>,
+		amount: Self::Balance,
+	) -> WithdrawConsequence<Self::Balance> {
+		if !ArtCollection::<T, I>::contains_key(asset) {
+			return WithdrawConsequence::UnknownAsset;
+		}
+		if ArtStatus::<T, I>::get(asset).locked {
+			return WithdrawConsequence::Frozen;
+		}
+		if NftBindInfos::<T, I>::get(who, asset) < amount {
+			return WithdrawConsequence::BalanceLow;
+		}
+		if NftCount::<T, I>::get(asset) < amount {
+			return WithdrawConsequence::Underflow;
+		}
+		WithdrawConsequence::Success
+	}
+
+	fn asset_exists(asset: Self::AssetId) -> bool {
+		ArtCollection::<T, I>::contains_key(asset)
+	}
+}
+
+impl<T: Config<I>, I: 'static> Unbalanced<BidOf<T, I>> for Pallet<T, I> {
+	/// The minimum balance is zero, so there is never any dust.
+	fn handle_dust(_dust: Dust<BidOf<T, I>, Self>) {}
+
+	fn write_balance(
+		asset: Self::AssetId,
+		who: &BidOf<T, I>,
+		amount: Self::Balance,
+	) -> Result<Option<Self::Balance>, DispatchError> {
+		if amount.is_zero() {
+			NftBindInfos::<T, I>::remove(who, asset);
+			NftHolders::<T, I>::remove(asset, who);
+		} else {
+			NftBindInfos::<T, I>::insert(who, asset, amount);
+			NftHolders::<T, I>::insert(asset, who, ());
+		}
+		Ok(None)
+	}
+
+	fn set_total_issuance(asset: Self::AssetId, amount: Self::Balance) {
+		if amount.is_zero() {
+			NftCount::<T, I>::remove(asset);
+		} else {
+			NftCount::<T, I>::insert(asset, amount);
+		}
+	}
+}
+
+impl<T: Config<I>, I: 'static> Mutate<BidOf<T, I>> for Pallet<T, I> {
+	/// Issues `amount` units within the supply rules of the collection.
+	fn mint_into(asset: Self::AssetId, who: &BidOf<T, I>, amount: Self::Balance) -> Result<Self::Balance, DispatchError> {
+		Self::can_deposit(asset, who, amount, Provenance::Minted).into_result()?;
+		let item = MintItem { b_id: who.clone(), s_id: asset, count: amount };
+		Self::mint_units(&item, &ArtStatus::<T, I>::get(asset)).map_err(Error::<T, I>::from)?;
+
+		let bn = <frame_system::Pallet<T>>::block_number();
+		Self::deposit_event(Event::MintArtOwner { bn, items: MintItemsOf::<T, I>::truncate_from(sp_std::vec![item]), request_id: None });
+		Ok(amount)
+	}
+
+	fn done_burn_from(asset: Self::AssetId, who: &BidOf<T, I>, amount: Self::Balance) {
+		let item = MintItem { b_id: who.clone(), s_id: asset, count: amount };
+		let bn = <frame_system::Pallet<T>>::block_number();
+		Self::deposit_event(Event::RevokeArtOwner {
+			bn,
+			items: MintItemsOf::<T, I>::truncate_from(sp_std::vec![item]),
+			reason: RevokeReason::Burn,
+		});
+	}
+
+	fn done_transfer(asset: Self::AssetId, source: &BidOf<T, I>, dest: &BidOf<T, I>, amount: Self::Balance) {
+		Self::deposit_event(Event::TransferArtOwner {
+			s_id: asset,
+			from_bid: source.clone(),
+			to_bid: dest.clone(),
+			count: amount,
+		});
+	}
+}
@@ -0,0 +1,77 @@
+//! Implementations of the `nonfungibles_v2` traits for pallet-eternal-arts.
+//!
+//! A collection is identified by its `s_id` and an item by the relation id holding units of it.
+//! Relation ids are held in custody by the collection owner, who is reported as the owner of
+//! every item of the collection.
+//!
+//! `Mutate` is not implemented: it mints an item to a recipient account, while units are issued
+//! to relation ids that no account owns. Units are issued and revoked through the calls.
+use super::*;
+use frame_support::dispatch::DispatchError;
+use frame_support::traits::tokens::nonfungibles_v2::{Create, Inspect};
+
+impl<T: Config<I>, I: 'static> Inspect<T::AccountId> for Pallet<T, I> {
+	type ItemId = BidOf<T, I>;
+	type CollectionId = TypeSid;
+
+	fn owner(collection: &Self::CollectionId, item: &Self::ItemId) -> Option<T::AccountId> {
+		if NftBindInfos::<T, I>::get(item, collection) == 0 {
+			return None;
+		}
+		CollectionOwner::<T, I>::get(collection)
+	}
+
+	fn collection_owner(collection: &Self::CollectionId) -> Option<T::AccountId> {
+		CollectionOwner::<T, I>::get(collection)
+	}
+
+	/// The `name`, `uri` and `content_hash` of the collection, then its `CollectionAttributes`.
+	fn collection_attribute(collection: &Self::CollectionId, key: &[u8]) -> Option<Vec<u8>> {
+		let data = ArtCollection::<T, I>::get(collection)?;
+		match key {
+			b"name" => Some(data.name.into_inner()),
+			b"uri" => Some(data.uri.into_inner()),
+			b"content_hash" => data.content_hash.map(|content_hash| content_hash.to_vec()),
+			_ => {
+				let key = AttributeKeyOf::<T, I>::try_from(key.to_vec()).ok()?;
+				CollectionAttributes::<T, I>::get(collection, key).map(|attribute| attribute.value.into_inner())
+			},
+		}
+	}
+
+	/// Only items holding units of an unlocked, transferable collection can be transferred.
+	fn can_transfer(collection: &Self::CollectionId, item: &Self::ItemId) -> bool {
+		if !ArtCollection::<T, I>::contains_key(collection) || NftBindInfos::<T, I>::get(item, collection) == 0 {
+			return false;
+		}
+		let art_status = ArtStatus::<T, I>::get(collection);
+		!art_status.locked && art_status.is_transferable()
+	}
+}
+
+impl<T: Config<I>, I: 'static> Create<T::AccountId, CollectionDataOf<T, I>> for Pallet<T, I> {
+	/// Creates a collection owned by `who` under the next free id, `admin` is made minter and
+	/// metadata editor of it. The collection is committed to the `content_hash` of the config.
+	fn create_collection(
+		who: &T::AccountId,
+		admin: &T::AccountId,
+		config: &CollectionDataOf<T, I>,
+	) -> Result<Self::CollectionId, DispatchError> {
+		if let Some(content_hash) = &config.content_hash {
+			ensure!(!ContentHashes::<T, I>::contains_key(content_hash), Error::<T, I>::DuplicateContentHash);
+		}
+		let s_id = NextCollectionId::<T, I>::get();
+		Self::do_create_art_collection(who.clone(), s_id, config.name.clone(), config.uri.clone())?;
+		if let Some(content_hash) = config.content_hash {
+			Self::do_register_content_hash(s_id, content_hash)?;
+		}
+
+		if admin != who {
+			for role in [AdminRole::Minter, AdminRole::MetadataEditor] {
+				CollectionAdministrators::<T, I>::mutate(s_id, admin, |roles| roles.insert(&role));
+				Self::deposit_event(Event::CollectionRoleGranted { s_id, who: admin.clone(), role });
+			}
+		}
+		Ok(s_id)
+	}
+}
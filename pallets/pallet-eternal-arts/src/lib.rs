@@ -5,6 +5,15 @@
 /// <https://docs.substrate.io/reference/frame-pallets/>
 pub use pallet::*;
 use frame_support::transactional;
+use frame_support::storage::{with_transaction, TransactionOutcome};
+use frame_support::dispatch::DispatchResult;
+use frame_support::{ensure, BoundedVec};
+use frame_support::traits::{Currency, ReservableCurrency};
+use frame_support::sp_runtime::traits::Saturating;
+use frame_support::weights::Weight;
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_std::vec::Vec;
+use uri::UriError;
 
 #[cfg(test)]
 mod mock;
@@ -14,6 +23,10 @@ mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+mod impl_fungibles;
+mod impl_nonfungibles;
+pub mod migrations;
+pub mod uri;
 pub mod weights;
 pub use weights::*;
 
@@ -21,83 +34,316 @@ pub use weights::*;
 pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::*;
-	use frame_support::sp_runtime::traits::BlockNumberProvider;
+	use frame_support::sp_runtime::traits::{BlockNumberProvider, Zero};
 	use frame_system::pallet_prelude::*;
 	use sp_std::vec::Vec;
+	#[cfg(feature = "std")]
+	use serde::{Deserialize, Serialize};
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(8);
+
+	/// Storage entries `do_issue_item` reads before it rejects an item, at worst:
+	/// `CollectionOwner`, `Administrators`, `AdministratorExpiries`, `CollectionAdministrators`,
+	/// `ArtCollection`, `ArtStatus`, `NftCount` and `NftBindInfos`.
+	const SKIPPED_ITEM_READS: u64 = 8;
+
+	/// The shortest `MaxNameLength` a runtime can configure.
+	pub const MIN_NAME_LENGTH: u32 = 16;
+	/// The shortest `MaxUriLength` a runtime can configure, enough for `ipfs://` and a CIDv1.
+	pub const MIN_URI_LENGTH: u32 = 66;
+	/// The shortest `MaxBidLength` a runtime can configure.
+	pub const MIN_BID_LENGTH: u32 = 8;
 
 	#[pallet::pallet]
-	pub struct Pallet<T>(_);
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
 
 	/// Configure the pallet by specifying the parameters and types on which it depends.
 	#[pallet::config]
-	pub trait Config: frame_system::Config {
+	pub trait Config<I: 'static = ()>: frame_system::Config {
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
-		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		type RuntimeEvent: From<Event<Self, I>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The maximum number of items in a mint or revoke batch.
+		#[pallet::constant]
+		type MaxBatch: Get<u32>;
+		/// How many blocks a processed mint request id is remembered for.
+		#[pallet::constant]
+		type RequestIdExpiry: Get<BlockNumberFor<Self>>;
+		/// The origin managing the administrators and force-creating collections.
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The maximum number of accounts holding a global administrator role.
+		#[pallet::constant]
+		type MaxAdministrators: Get<u32>;
+		/// The currency attribute deposits are reserved in.
+		type Currency: ReservableCurrency<Self::AccountId>;
+		/// The deposit reserved per byte of an attribute key and value.
+		#[pallet::constant]
+		type AttributeDepositPerByte: Get<BalanceOf<Self, I>>;
+		/// The maximum length of an attribute key.
+		#[pallet::constant]
+		type MaxAttributeKeyLength: Get<u32>;
+		/// The maximum length of an attribute value.
+		#[pallet::constant]
+		type MaxAttributeValueLength: Get<u32>;
+		/// The maximum number of attributes of a collection.
 		#[pallet::constant]
-		type BatchMintSize: Get<u8>;
+		type MaxAttributes: Get<u32>;
+		/// The maximum length of a collection name.
+		#[pallet::constant]
+		type MaxNameLength: Get<u32>;
+		/// The maximum length of a collection uri.
+		#[pallet::constant]
+		type MaxUriLength: Get<u32>;
+		/// The maximum length of a relation id.
+		#[pallet::constant]
+		type MaxBidLength: Get<u32>;
+		/// The schemes a collection uri may start with, including the `://` separator.
+		type UriSchemes: Get<&'static [&'static [u8]]>;
 		/// Type representing the weight of this pallet
 		type WeightInfo: WeightInfo;
 	}
 
-	pub type TypeCollectionDataLength = ConstU32<100>;
-	pub type TypeBidLength = ConstU32<100>;
-
 	pub type TypeNftCount = u64;
-	pub type TypeBid = BoundedVec<u8, TypeBidLength>;
 	pub type TypeSid = u64;
 	pub type TypeCount = u64;
+	pub type TypeRequestIdLength = ConstU32<64>;
+	pub type TypeRequestId = BoundedVec<u8, TypeRequestIdLength>;
+	pub type TypeDigestLength = ConstU32<64>;
+	/// A blake2-256 hash of an artwork file.
+	pub type TypeContentHash = [u8; 32];
+
+	pub type NameOf<T, I = ()> = BoundedVec<u8, <T as Config<I>>::MaxNameLength>;
+	pub type UriOf<T, I = ()> = BoundedVec<u8, <T as Config<I>>::MaxUriLength>;
+	pub type BidOf<T, I = ()> = BoundedVec<u8, <T as Config<I>>::MaxBidLength>;
+	pub type CollectionDataOf<T, I = ()> = StructArtCollectionData<NameOf<T, I>, UriOf<T, I>>;
+
+	pub type MintItemOf<T, I = ()> = MintItem<BidOf<T, I>>;
+	pub type MintItemsOf<T, I = ()> = BoundedVec<MintItemOf<T, I>, <T as Config<I>>::MaxBatch>;
+	pub type AdministratorListOf<T, I = ()> =
+		BoundedVec<(<T as frame_system::Config>::AccountId, AdminRole), <T as Config<I>>::MaxAdministrators>;
+
+	pub type BalanceOf<T, I = ()> =
+		<<T as Config<I>>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	pub type AttributeKeyOf<T, I = ()> = BoundedVec<u8, <T as Config<I>>::MaxAttributeKeyLength>;
+	pub type AttributeValueOf<T, I = ()> = BoundedVec<u8, <T as Config<I>>::MaxAttributeValueLength>;
+
+	/// One entry of a mint or revoke batch, `count` units of `s_id` for `b_id`.
+	#[derive(Encode, Decode, RuntimeDebug, Clone, PartialEq, TypeInfo, Eq, MaxEncodedLen)]
+	pub struct MintItem<Bid> {
+		pub b_id: Bid,
+		pub s_id: TypeSid,
+		pub count: TypeCount,
+	}
+
+	/// Why a single item of a batch was rejected.
+	#[derive(Encode, Decode, PalletError, RuntimeDebug, Clone, Copy, PartialEq, TypeInfo, Eq, MaxEncodedLen)]
+	pub enum MintItemError {
+		NotAdministrator,
+		ArtCollectionNotFound,
+		ArtCollectionIsLocked,
+		ArtCollectionLimitNotSet,
+		ArtCollectionLimitExceeded,
+		InsufficientOwnership,
+		StorageOverflow,
+		StorageUnderflow,
+	}
+
+	/// A role that can be granted to an administrator.
+	#[derive(Encode, Decode, RuntimeDebug, Clone, Copy, PartialEq, TypeInfo, Eq, MaxEncodedLen)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub enum AdminRole {
+		/// Creates collections and manages their limit and type.
+		#[codec(index = 0)]
+		Creator,
+		/// Issues, transfers and revokes art ownership.
+		#[codec(index = 1)]
+		Minter,
+		/// Locks and unlocks collections.
+		#[codec(index = 2)]
+		Locker,
+		/// Edits the name and uri of collections.
+		#[codec(index = 3)]
+		MetadataEditor,
+	}
+
+	impl AdminRole {
+		/// The bit of the role inside `AdminRoles`.
+		pub fn flag(&self) -> u8 {
+			1 << (*self as u8)
+		}
+	}
 
-	pub type TypeSidList = Vec<TypeSid>;
-	pub type TypeBidList = Vec<TypeBid>;
-	pub type TypeCountList = Vec<TypeCount>;
+	/// The set of roles held by an administrator, one bit per `AdminRole`.
+	#[derive(Encode, Decode, RuntimeDebug, Clone, Copy, Default, PartialEq, TypeInfo, Eq, MaxEncodedLen)]
+	pub struct AdminRoles(pub u8);
 
-	pub const ADMIN_TYPE_IS_CREATOR: u8 = 0;
-	pub const ADMIN_TYPE_IS_MINTER: u8 = 1;
+	impl AdminRoles {
+		pub fn contains(&self, role: &AdminRole) -> bool {
+			self.0 & role.flag() != 0
+		}
+
+		pub fn insert(&mut self, role: &AdminRole) {
+			self.0 |= role.flag();
+		}
+
+		pub fn remove(&mut self, role: &AdminRole) {
+			self.0 &= !role.flag();
+		}
+
+		pub fn is_empty(&self) -> bool {
+			self.0 == 0
+		}
+	}
 
 
 	#[derive(Encode, Decode, RuntimeDebug, Clone, PartialEq, TypeInfo, Eq, MaxEncodedLen)]
-	pub struct StructArtCollectionData<Type> {
-		pub name: BoundedVec<Type, TypeCollectionDataLength>,
-		pub uri: BoundedVec<Type, TypeCollectionDataLength>,
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub struct StructArtCollectionData<Name, Uri> {
+		pub name: Name,
+		pub uri: Uri,
+		/// The multihash of the CID of an `ipfs://` uri.
+		pub multihash: Option<Multihash>,
+		/// The artwork file the collection is committed to, it can not be changed once set.
+		pub content_hash: Option<TypeContentHash>,
 	}
 
+	/// A self-describing hash, `code` is the multicodec of the hash function.
 	#[derive(Encode, Decode, RuntimeDebug, Clone, PartialEq, TypeInfo, Eq, MaxEncodedLen)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub struct Multihash {
+		pub code: u64,
+		pub digest: BoundedVec<u8, TypeDigestLength>,
+	}
+
+	/// The kind of an art collection, it decides how the units of the collection can be issued and moved.
+	#[derive(Encode, Decode, RuntimeDebug, Clone, Copy, PartialEq, TypeInfo, Eq, MaxEncodedLen, Default)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub enum ArtType {
+		/// Any number of units can be issued, optionally capped by `limit`.
+		#[default]
+		#[codec(index = 0)]
+		OpenEdition,
+		/// A fixed number of units, the `limit` must be set before anything is issued.
+		#[codec(index = 1)]
+		LimitedEdition,
+		/// A 1-of-1 piece, only one unit can be issued in total.
+		#[codec(index = 2)]
+		Unique,
+		/// Units stay bound to the relation id they were issued to and can not be transferred.
+		#[codec(index = 3)]
+		Soulbound,
+	}
+
+	/// Why issued units were taken back, recorded in the `RevokeArtOwner` event.
+	#[derive(Encode, Decode, RuntimeDebug, Clone, Copy, PartialEq, TypeInfo, Eq, MaxEncodedLen)]
+	pub enum RevokeReason {
+		/// The units were issued by mistake.
+		#[codec(index = 0)]
+		Mistake,
+		/// The issuance was fraudulent.
+		#[codec(index = 1)]
+		Fraud,
+		/// The holder asked for the units to be burned.
+		#[codec(index = 2)]
+		Burn,
+		/// Any other reason, the code is defined off chain.
+		#[codec(index = 3)]
+		Other(u8),
+	}
+
+	#[derive(Encode, Decode, RuntimeDebug, Clone, PartialEq, TypeInfo, Eq, MaxEncodedLen, Default)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 	pub struct StructArtStatus {
-		limit: u64,
-		art_type: u8,
-		locked: bool,
+		/// Maximum number of units that may ever be issued, `None` means unlimited.
+		pub limit: Option<TypeCount>,
+		pub art_type: ArtType,
+		pub locked: bool,
 	}
 
-	impl Default for StructArtStatus {
-		fn default() -> Self {
-			StructArtStatus{
-				limit: 0,
-				art_type: 0,
-				locked: false,
+	/// The value of a collection attribute and the deposit reserved from `depositor` for it.
+	#[derive(Encode, Decode, RuntimeDebug, Clone, PartialEq, TypeInfo, Eq, MaxEncodedLen)]
+	pub struct CollectionAttribute<Value, AccountId, Balance> {
+		pub value: Value,
+		pub depositor: AccountId,
+		pub deposit: Balance,
+	}
+
+	/// A collection as served by the runtime API.
+	#[derive(Encode, Decode, RuntimeDebug, Clone, PartialEq, TypeInfo, Eq)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub struct CollectionInfo<AccountId> {
+		pub data: StructArtCollectionData<Vec<u8>, Vec<u8>>,
+		pub status: StructArtStatus,
+		pub owner: Option<AccountId>,
+		pub total_supply: TypeCount,
+	}
+
+	impl StructArtStatus {
+		/// The supply limit that applies to the collection, taking its `art_type` into account.
+		pub fn max_supply(&self) -> Option<TypeCount> {
+			match self.art_type {
+				ArtType::Unique => Some(self.limit.map_or(1, |limit| limit.min(1))),
+				_ => self.limit,
 			}
 		}
+
+		/// Whether units of the collection can move between relation ids.
+		pub fn is_transferable(&self) -> bool {
+			self.art_type != ArtType::Soulbound
+		}
 	}
 
 
 	#[pallet::storage]
-	#[pallet::getter(fn administrator_list)]
-	#[pallet::unbounded]
-	pub type AdministratorList<T: Config> = StorageValue<_, Vec<(T::AccountId, u8)>>;
+	#[pallet::getter(fn administrators)]
+	pub type Administrators<T: Config<I>, I: 'static = ()> = CountedStorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		AdminRoles,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_collection_id)]
+	pub type NextCollectionId<T: Config<I>, I: 'static = ()> = StorageValue<_, TypeSid, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn collection_owner)]
+	pub type CollectionOwner<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		TypeSid,
+		T::AccountId,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn collection_administrators)]
+	pub type CollectionAdministrators<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		TypeSid,
+		Blake2_128Concat,
+		T::AccountId,
+		AdminRoles,
+		ValueQuery,
+	>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn art_collection)]
-	pub type ArtCollection<T: Config> = StorageMap<
+	pub type ArtCollection<T: Config<I>, I: 'static = ()> = StorageMap<
 		_,
 		Twox64Concat,
 		u64,
-		StructArtCollectionData<u8>,
+		CollectionDataOf<T, I>,
 		OptionQuery,
 	>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn art_satatus)]
-	pub type ArtStatus<T: Config> = StorageMap<
+	pub type ArtStatus<T: Config<I>, I: 'static = ()> = StorageMap<
 		_,
 		Twox64Concat,
 		u64,
@@ -107,7 +353,7 @@ pub mod pallet {
 
 	#[pallet::storage]
 	#[pallet::getter(fn nft_count)]
-	pub type NftCount<T> = StorageMap<
+	pub type NftCount<T: Config<I>, I: 'static = ()> = StorageMap<
 		_,
 		Twox64Concat,
 		TypeSid, // s_id
@@ -117,59 +363,243 @@ pub mod pallet {
 
 	#[pallet::storage]
 	#[pallet::getter(fn nft_bind_infos)]
-	pub type NftBindInfos<T> = StorageMap<
+	pub type NftBindInfos<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		BidOf<T, I>, // b_id
+		Twox64Concat,
+		TypeSid, // s_id
+		TypeCount, // count
+		ValueQuery,
+	>;
+
+	/// Reverse index of `NftBindInfos`, lists the relation ids holding a collection.
+	#[pallet::storage]
+	#[pallet::getter(fn nft_holders)]
+	pub type NftHolders<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		TypeSid, // s_id
+		Blake2_128Concat,
+		BidOf<T, I>, // b_id
+		(),
+		OptionQuery,
+	>;
+
+	/// Key-value attributes of a collection.
+	#[pallet::storage]
+	pub type CollectionAttributes<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		TypeSid,
+		Blake2_128Concat,
+		AttributeKeyOf<T, I>,
+		CollectionAttribute<AttributeValueOf<T, I>, T::AccountId, BalanceOf<T, I>>,
+		OptionQuery,
+	>;
+
+	/// The number of `CollectionAttributes` of a collection.
+	#[pallet::storage]
+	pub type AttributeCount<T: Config<I>, I: 'static = ()> = StorageMap<
 		_,
 		Twox64Concat,
-		(TypeBid, TypeSid), // b_id
-		TypeCount, // s_id
+		TypeSid,
+		u32,
 		ValueQuery,
 	>;
 
+	/// The collection committed to each artwork content hash.
+	#[pallet::storage]
+	pub type ContentHashes<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		TypeContentHash,
+		TypeSid,
+		OptionQuery,
+	>;
+
+	/// External request ids of processed mint batches per caller, with the block they were
+	/// processed in. Callers can not use up the request ids of each other.
+	#[pallet::storage]
+	pub type ProcessedRequests<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		TypeRequestId,
+		BlockNumberFor<T>,
+		OptionQuery,
+	>;
+
+	/// `ProcessedRequests` keyed by the block they expire at, for pruning in `on_idle`.
+	#[pallet::storage]
+	pub type RequestExpiries<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		Blake2_128Concat,
+		(T::AccountId, TypeRequestId),
+		(),
+		OptionQuery,
+	>;
 
+	/// The earliest block of `RequestExpiries` that has not been pruned, if any is pending.
+	#[pallet::storage]
+	pub type NextRequestExpiry<T: Config<I>, I: 'static = ()> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	/// The block a global administrator loses its roles at, absent for permanent administrators.
+	#[pallet::storage]
+	pub type AdministratorExpiries<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BlockNumberFor<T>,
+		OptionQuery,
+	>;
+
+	/// `AdministratorExpiries` keyed by the expiry block, for pruning in `on_idle`.
+	#[pallet::storage]
+	pub type AdministratorsByExpiry<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
+	/// The earliest block of `AdministratorsByExpiry` that has not been pruned, if any is pending.
+	#[pallet::storage]
+	pub type NextAdministratorExpiry<T: Config<I>, I: 'static = ()> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
 
 	// Pallets use events to inform users when important changes are made.
 	// https://docs.substrate.io/main-docs/build/events-errors/
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
-	pub enum Event<T: Config> {
+	pub enum Event<T: Config<I>, I: 'static = ()> {
 		/// Event documentation should end with an array that provides descriptive names for event
 		/// parameters. [something, who]
 		SomethingStored { something: u32, who: T::AccountId },
 
 		ArtCollectionCreated {
 			s_id: u64,
-			name: BoundedVec<u8, TypeCollectionDataLength>,
-			uri: BoundedVec<u8, TypeCollectionDataLength>,
+			owner: T::AccountId,
+			name: NameOf<T, I>,
+			uri: UriOf<T, I>,
 		},
 
 		ArtCollectionUpdated {
 			s_id: u64,
-			name: BoundedVec<u8, TypeCollectionDataLength>,
-			uri: BoundedVec<u8, TypeCollectionDataLength>,
+			name: NameOf<T, I>,
+			uri: UriOf<T, I>,
 		},
 
 		MintArtOwner {
 			bn: <frame_system::Pallet<T>  as BlockNumberProvider>::BlockNumber,
-			b_ids: TypeBidList,
-			s_ids: TypeSidList,
-			count: TypeCountList,
+			items: MintItemsOf<T, I>,
+			request_id: Option<TypeRequestId>,
+		},
+
+		/// A best-effort batch was processed, `results[i]` is the outcome of `items[i]`.
+		MintArtOwnerBestEffort {
+			bn: <frame_system::Pallet<T>  as BlockNumberProvider>::BlockNumber,
+			items: MintItemsOf<T, I>,
+			results: BoundedVec<Result<(), MintItemError>, T::MaxBatch>,
+			request_id: Option<TypeRequestId>,
+		},
+
+		RevokeArtOwner {
+			bn: <frame_system::Pallet<T>  as BlockNumberProvider>::BlockNumber,
+			items: MintItemsOf<T, I>,
+			reason: RevokeReason,
+		},
+
+		TransferArtOwner {
+			s_id: TypeSid,
+			from_bid: BidOf<T, I>,
+			to_bid: BidOf<T, I>,
+			count: TypeCount,
 		},
 
 		UpdateAdministratorList {
-			administrator_list: Vec<(T::AccountId ,u8)>,
+			administrator_list: AdministratorListOf<T, I>,
+		},
+
+		RoleGranted {
+			who: T::AccountId,
+			role: AdminRole,
+		},
+
+		RoleRevoked {
+			who: T::AccountId,
+			role: AdminRole,
+		},
+
+		AdministratorAdded {
+			who: T::AccountId,
+			role: AdminRole,
+		},
+
+		/// `roles` are the roles the administrator held when it was removed.
+		AdministratorRemoved {
+			who: T::AccountId,
+			roles: AdminRoles,
+		},
+
+		/// `None` makes the administrator permanent again.
+		AdministratorExpirySet {
+			who: T::AccountId,
+			expires_at: Option<BlockNumberFor<T>>,
+		},
+
+		/// The administrator reached its expiry and was removed with `roles`.
+		AdministratorExpired {
+			who: T::AccountId,
+			roles: AdminRoles,
+		},
+
+		CollectionRoleGranted {
+			s_id: TypeSid,
+			who: T::AccountId,
+			role: AdminRole,
+		},
+
+		CollectionRoleRevoked {
+			s_id: TypeSid,
+			who: T::AccountId,
+			role: AdminRole,
 		},
 
 		UpdateArtStatus {
 			s_id: u64,
-			limit: u64,
-			art_type: u8,
+			limit: Option<TypeCount>,
+			art_type: ArtType,
 			locked: bool,
 		},
+
+		/// `deposit` was reserved from the editor for the attribute.
+		AttributeSet {
+			s_id: TypeSid,
+			key: AttributeKeyOf<T, I>,
+			value: AttributeValueOf<T, I>,
+			deposit: BalanceOf<T, I>,
+		},
+
+		AttributeCleared {
+			s_id: TypeSid,
+			key: AttributeKeyOf<T, I>,
+		},
+
+		ContentHashRegistered {
+			s_id: TypeSid,
+			content_hash: TypeContentHash,
+		},
 	}
 
 	// Errors inform users that something went wrong.
 	#[pallet::error]
-	pub enum Error<T> {
+	pub enum Error<T, I = ()> {
 		/// Error names should be descriptive.
 		NoneValue,
 		/// Errors should have helpful documentation associated with them.
@@ -182,59 +612,184 @@ pub mod pallet {
 		ArtCollectionIsLocked,
 		//
 		ArtCollectionIsExists,
-		//
-		LengthNotMatch,
-		//
+		/// The batch holds more than `MaxBatch` items.
 		BatchSizeExceeded,
+		/// Issuing would take `NftCount` of the collection beyond its limit.
+		ArtCollectionLimitExceeded,
+		/// The new limit is lower than the number of units already issued.
+		ArtCollectionLimitTooLow,
+		/// A limited edition collection needs a limit before it can be issued.
+		ArtCollectionLimitNotSet,
+		/// The collection already has issued units, its type can not change any more.
+		ArtCollectionAlreadyIssued,
+		/// The art type of the collection does not allow transfers.
+		ArtCollectionNotTransferable,
+		/// The relation id does not hold enough units of the collection.
+		InsufficientOwnership,
+		/// The count must be greater than zero.
+		InvalidCount,
+		/// The account already holds the role.
+		RoleAlreadyGranted,
+		/// The account does not hold the role.
+		RoleNotGranted,
+		/// Only minters and metadata editors can be appointed for a single collection.
+		RoleNotAssignable,
+		/// The item at `index` of the batch was rejected, nothing of the batch was applied.
+		MintItemFailed { index: u16, error: MintItemError },
+		/// A mint batch with this request id was already processed.
+		RequestAlreadyProcessed,
+		/// `MaxAdministrators` accounts already hold a role.
+		TooManyAdministrators,
+		/// The account already holds a role, use `grant_role` to add more.
+		AdministratorAlreadyExists,
+		/// The account holds no role.
+		AdministratorNotFound,
+		/// The expiry block has already been reached.
+		ExpiryInPast,
+		/// The collection already has `MaxAttributes` attributes.
+		TooManyAttributes,
+		/// The collection has no attribute with this key.
+		AttributeNotFound,
+		/// The uri contains spaces, control or non-ASCII characters.
+		UriInvalidCharacter,
+		/// The uri does not start with one of the `UriSchemes`.
+		UriSchemeNotAllowed,
+		/// Nothing follows the scheme of the uri.
+		UriMissingTarget,
+		/// The CID of the `ipfs://` uri is neither a CIDv0 nor base32 or base58btc.
+		CidUnsupportedMultibase,
+		/// The CID of the `ipfs://` uri is not valid in its multibase.
+		CidInvalidEncoding,
+		/// The CID of the `ipfs://` uri is neither version 0 nor 1.
+		CidUnsupportedVersion,
+		/// The multihash of the CID is malformed or its digest is longer than 64 bytes.
+		CidInvalidMultihash,
+		/// The collection is already committed to a content hash.
+		ContentHashAlreadySet,
+		/// Another collection is committed to this content hash.
+		DuplicateContentHash,
+		/// The batch holds no items.
+		EmptyBatch,
+		/// The supply of the collection is lower than the units being revoked.
+		StorageUnderflow,
+	}
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
+		#[serde(skip)]
+		pub phantom: PhantomData<I>,
+		/// Global administrators with their roles.
+		pub administrators: Vec<(T::AccountId, Vec<AdminRole>)>,
+		/// `(s_id, owner, name, uri)` of the initial collections.
+		pub collections: Vec<(TypeSid, T::AccountId, Vec<u8>, Vec<u8>)>,
+		/// `(s_id, limit, art_type, locked)` of the initial collections.
+		pub statuses: Vec<(TypeSid, Option<TypeCount>, ArtType, bool)>,
+		/// `(b_id, s_id, count)` units held at genesis, within the supply rules of the collection.
+		pub holdings: Vec<(Vec<u8>, TypeSid, TypeCount)>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config<I>, I: 'static> BuildGenesisConfig for GenesisConfig<T, I> {
+		fn build(&self) {
+			for (who, roles) in self.administrators.iter() {
+				assert!(!roles.is_empty(), "Genesis administrator without roles");
+				assert!(!Administrators::<T, I>::contains_key(who), "Duplicate genesis administrator");
+				let mut admin_roles = AdminRoles::default();
+				for role in roles.iter() {
+					admin_roles.insert(role);
+				}
+				Administrators::<T, I>::insert(who, admin_roles);
+			}
+			assert!(
+				Administrators::<T, I>::count() <= T::MaxAdministrators::get(),
+				"More genesis administrators than MaxAdministrators"
+			);
+
+			for (s_id, owner, name, uri) in self.collections.iter() {
+				let name = name.clone().try_into().expect("Genesis collection name is too long");
+				let uri = uri.clone().try_into().expect("Genesis collection uri is too long");
+				Pallet::<T, I>::do_create_art_collection(owner.clone(), *s_id, name, uri)
+					.unwrap_or_else(|error| panic!("Invalid genesis collection {}: {:?}", s_id, error));
+			}
+
+			for (s_id, limit, art_type, locked) in self.statuses.iter() {
+				assert!(ArtCollection::<T, I>::contains_key(s_id), "Genesis status of unknown collection {}", s_id);
+				ArtStatus::<T, I>::insert(s_id, StructArtStatus { limit: *limit, art_type: *art_type, locked: *locked });
+			}
+
+			for (b_id, s_id, count) in self.holdings.iter() {
+				assert!(ArtCollection::<T, I>::contains_key(s_id), "Genesis holding of unknown collection {}", s_id);
+				assert!(*count > 0, "Empty genesis holding of collection {}", s_id);
+				let b_id = b_id.clone().try_into().expect("Genesis relation id is too long");
+				Pallet::<T, I>::mint_units(&MintItem { b_id, s_id: *s_id, count: *count }, &ArtStatus::<T, I>::get(s_id))
+					.unwrap_or_else(|error| panic!("Invalid genesis holding of collection {}: {:?}", s_id, error));
+			}
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		fn integrity_test() {
+			assert!(T::MaxBatch::get() > 0, "`MaxBatch` must allow at least one item");
+			// `MintItemFailed` reports the index as `u16`.
+			assert!(T::MaxBatch::get() <= u16::MAX as u32, "`MaxBatch` must fit in a u16");
+			assert!(!T::RequestIdExpiry::get().is_zero(), "`RequestIdExpiry` must be at least one block");
+			assert!(T::MaxAdministrators::get() > 0, "`MaxAdministrators` must allow at least one administrator");
+			assert!(T::MaxAttributeKeyLength::get() > 0, "`MaxAttributeKeyLength` must allow a key");
+			assert!(T::MaxNameLength::get() >= MIN_NAME_LENGTH, "`MaxNameLength` is shorter than `MIN_NAME_LENGTH`");
+			assert!(T::MaxUriLength::get() >= MIN_URI_LENGTH, "`MaxUriLength` is shorter than `MIN_URI_LENGTH`");
+			assert!(T::MaxBidLength::get() >= MIN_BID_LENGTH, "`MaxBidLength` is shorter than `MIN_BID_LENGTH`");
+			assert!(!T::UriSchemes::get().is_empty(), "`UriSchemes` must allow at least one scheme");
+		}
+
+		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let used = Self::prune_expired_administrators(now, remaining_weight);
+			used.saturating_add(Self::prune_processed_requests(now, remaining_weight.saturating_sub(used)))
+		}
 	}
 
 	#[pallet::call]
-	impl<T: Config> Pallet<T> {
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		#[pallet::call_index(0)]
-		#[pallet::weight(T::WeightInfo::do_something())]
+		#[pallet::weight(T::WeightInfo::create_art_collection(name.len() as u32, uri.len() as u32))]
 		pub fn create_art_collection(
 			origin: OriginFor<T>,
-			s_id: TypeSid,
-			name: BoundedVec<u8, TypeCollectionDataLength>,
-			uri: BoundedVec<u8, TypeCollectionDataLength>
+			name: NameOf<T, I>,
+			uri: UriOf<T, I>
 		) -> DispatchResult {
 
 			let who = ensure_signed(origin)?;
-			ensure!(Self::is_administrator_list(&who, &ADMIN_TYPE_IS_CREATOR), Error::<T>::NotAdministrator);
-
-			ensure!(!ArtCollection::<T>::contains_key(s_id.clone()), Error::<T>::ArtCollectionIsExists);
+			ensure!(Self::is_administrator_list(&who, &AdminRole::Creator), Error::<T, I>::NotAdministrator);
 
-			// Update storage.
-			<ArtCollection<T>>::insert(s_id.clone(), StructArtCollectionData{
-				name: name.clone(),
-				uri: uri.clone(),
-			});
-
-			// Emit an event.
-			Self::deposit_event(Event::ArtCollectionCreated { s_id, name, uri });
-			Ok(())
+			// Allocate the next free id.
+			let s_id = NextCollectionId::<T, I>::get();
+			Self::do_create_art_collection(who, s_id, name, uri)
 		}
 
 		#[pallet::call_index(1)]
-		#[pallet::weight(T::WeightInfo::do_something())]
+		#[pallet::weight(T::WeightInfo::update_art_collection(name.len() as u32, uri.len() as u32))]
 		pub fn update_art_collection(
 			origin: OriginFor<T>,
 			s_id: TypeSid,
-			name: BoundedVec<u8, TypeCollectionDataLength>,
-			uri: BoundedVec<u8, TypeCollectionDataLength>,
+			name: NameOf<T, I>,
+			uri: UriOf<T, I>,
 		) -> DispatchResult {
 
 			let who = ensure_signed(origin)?;
-			ensure!(Self::is_administrator_list(&who, &ADMIN_TYPE_IS_CREATOR), Error::<T>::NotAdministrator);
+			ensure!(Self::is_collection_administrator(&who, &s_id, &AdminRole::MetadataEditor), Error::<T, I>::NotAdministrator);
 
 			// Get art collection data.
-			ensure!(ArtCollection::<T>::contains_key(s_id.clone()), Error::<T>::ArtCollectionNotFound);
+			let data = ArtCollection::<T, I>::get(s_id).ok_or(Error::<T, I>::ArtCollectionNotFound)?;
+			let multihash = Self::validate_uri(&uri)?;
 
 			// Update storage.
-			<ArtCollection<T>>::insert(s_id.clone(), StructArtCollectionData{
+			<ArtCollection<T, I>>::insert(s_id, StructArtCollectionData{
 				name: name.clone(),
 				uri: uri.clone(),
+				multihash,
+				content_hash: data.content_hash,
 			});
 
 			// Emit an event.
@@ -243,61 +798,62 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(2)]
-		#[pallet::weight(T::WeightInfo::do_something())]
+		#[pallet::weight(T::WeightInfo::issue_art_ownership(items.len() as u32))]
 		#[transactional]
 		pub fn issue_art_ownership(
 			origin: OriginFor<T>,
-			b_ids: TypeBidList,
-			s_ids: TypeSidList,
-			count: TypeCountList,
+			items: MintItemsOf<T, I>,
+			request_id: Option<TypeRequestId>,
 		) -> DispatchResult {
 
 			let who = ensure_signed(origin)?;
-			ensure!(Self::is_administrator_list(&who, &ADMIN_TYPE_IS_MINTER), Error::<T>::NotAdministrator);
-
-			ensure!(s_ids.iter().count() > 0 && s_ids.iter().count() < T::BatchMintSize::get().into(), Error::<T>::BatchSizeExceeded);
-			ensure!(s_ids.iter().count() == b_ids.iter().count(), Error::<T>::LengthNotMatch);
-			ensure!(s_ids.iter().count() == count.iter().count(), Error::<T>::LengthNotMatch);
-
-			// Check s_ids exists
-			for s_id in s_ids.iter() {
-				ensure!(ArtCollection::<T>::contains_key(s_id.clone()), Error::<T>::ArtCollectionNotFound);
-				// Check ArtStatus locked status
-				ensure!(ArtStatus::<T>::get(s_id.clone()).locked == false, Error::<T>::ArtCollectionIsLocked);
+			ensure!(!items.is_empty(), Error::<T, I>::EmptyBatch);
+			if let Some(request_id) = &request_id {
+				Self::ensure_request_new(&who, request_id)?;
+				Self::note_request(&who, request_id);
 			}
 
-			for (com_id, c) in b_ids.iter().zip(s_ids.iter()).zip(count.iter()) {
-				// Get old count
-				let old_count = NftCount::<T>::get(com_id.1.clone());
-				let new_count = old_count.saturating_add(c.clone());
-				if new_count.saturating_sub(c.clone()) != old_count {
-					return Err(Error::<T>::StorageOverflow.into());
-				}
-				NftCount::<T>::insert(com_id.1.clone(), new_count);
-
-				let personal_old_count = NftBindInfos::<T>::get((com_id.0.clone(), com_id.1.clone()));
-				NftBindInfos::<T>::insert((com_id.0.clone(), com_id.1.clone()),  personal_old_count.saturating_add(c.clone()));
+			for (index, item) in items.iter().enumerate() {
+				Self::do_issue_item(&who, item)
+					.map_err(|error| Error::<T, I>::MintItemFailed { index: index as u16, error })?;
 			}
 			// Get current block number
 			let bn = <frame_system::Pallet<T>>::block_number();
 			// Emit an event.
-			Self::deposit_event(Event::MintArtOwner { bn, s_ids, b_ids, count });
+			Self::deposit_event(Event::MintArtOwner { bn, items, request_id });
 			Ok(())
 		}
 
+		/// Replaces every global administrator, prefer `add_administrator` and `remove_administrator`.
+		/// The list holds at most `MaxAdministrators` entries, one per account and role.
+		///
+		/// Charged for clearing `MaxAdministrators` existing administrators. Fails with
+		/// `TooManyAdministrators` while more accounts hold a role, remove them first.
 		#[pallet::call_index(3)]
-		#[pallet::weight(T::WeightInfo::do_something())]
+		#[pallet::weight(T::WeightInfo::update_administrator_list(
+			T::MaxAdministrators::get(),
+			administrator_list.len() as u32,
+		))]
+		#[transactional]
 		pub fn update_administrator_list(
 			origin: OriginFor<T>,
-			administrator_list: Vec<(T::AccountId, u8)>
+			administrator_list: AdministratorListOf<T, I>
 		) -> DispatchResult {
-			ensure_root(origin)?;
+			T::AdminOrigin::ensure_origin(origin)?;
 
-			// Update storage.
-			if administrator_list.iter().count() == 0 {
-				AdministratorList::<T>::set(Option::None);
-			} else {
-				AdministratorList::<T>::set(Option::Some(administrator_list.clone()));
+			// Update storage, the new list starts without expiries. Expiries only exist for
+			// administrators, so none of the maps holds more entries than `Administrators`.
+			let limit = T::MaxAdministrators::get();
+			ensure!(
+				Administrators::<T, I>::clear(limit, None).maybe_cursor.is_none(),
+				Error::<T, I>::TooManyAdministrators
+			);
+			let _ = AdministratorExpiries::<T, I>::clear(limit, None);
+			let _ = AdministratorsByExpiry::<T, I>::clear(limit, None);
+			NextAdministratorExpiry::<T, I>::kill();
+			// At most `MaxAdministrators` entries, so at most as many accounts.
+			for (who, role) in administrator_list.iter() {
+				Administrators::<T, I>::mutate(who, |roles| roles.insert(role));
 			}
 
 			// Emit an event.
@@ -307,7 +863,7 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(4)]
-		#[pallet::weight(T::WeightInfo::do_something())]
+		#[pallet::weight(T::WeightInfo::set_collection_status())]
 		pub fn set_collection_status(
 			origin: OriginFor<T>,
 			s_id: TypeSid,
@@ -315,15 +871,16 @@ pub mod pallet {
 		) -> DispatchResult {
 
 			let who = ensure_signed(origin)?;
-			ensure!(Self::is_administrator_list(&who, &ADMIN_TYPE_IS_CREATOR), Error::<T>::NotAdministrator);
+			ensure!(ArtCollection::<T, I>::contains_key(s_id), Error::<T, I>::ArtCollectionNotFound);
+			ensure!(Self::is_collection_administrator(&who, &s_id, &AdminRole::Locker), Error::<T, I>::NotAdministrator);
 
-			let mut old_art_status = ArtStatus::<T>::get(s_id.clone());
+			let mut old_art_status = ArtStatus::<T, I>::get(s_id);
 			old_art_status.locked = locked;
-			ArtStatus::<T>::insert(s_id.clone(), old_art_status.clone());
+			ArtStatus::<T, I>::insert(s_id, old_art_status.clone());
 
 			// Emit an event.
 			Self::deposit_event(Event::UpdateArtStatus {
-				s_id: s_id,
+				s_id,
 				limit: old_art_status.limit,
 				art_type: old_art_status.art_type,
 				locked: old_art_status.locked,
@@ -331,17 +888,817 @@ pub mod pallet {
 			// Return a successful DispatchResultWithPostInfo
 			Ok(())
 		}
+
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::set_collection_limit())]
+		pub fn set_collection_limit(
+			origin: OriginFor<T>,
+			s_id: TypeSid,
+			limit: Option<TypeCount>,
+		) -> DispatchResult {
+
+			let who = ensure_signed(origin)?;
+			ensure!(Self::is_collection_owner(&who, &s_id), Error::<T, I>::NotAdministrator);
+
+			ensure!(ArtCollection::<T, I>::contains_key(s_id), Error::<T, I>::ArtCollectionNotFound);
+			// The limit can not go below what has already been issued.
+			if let Some(limit) = limit {
+				ensure!(limit >= NftCount::<T, I>::get(s_id), Error::<T, I>::ArtCollectionLimitTooLow);
+			}
+
+			let mut old_art_status = ArtStatus::<T, I>::get(s_id);
+			old_art_status.limit = limit;
+			ArtStatus::<T, I>::insert(s_id, old_art_status.clone());
+
+			// Emit an event.
+			Self::deposit_event(Event::UpdateArtStatus {
+				s_id,
+				limit: old_art_status.limit,
+				art_type: old_art_status.art_type,
+				locked: old_art_status.locked,
+			});
+			Ok(())
+		}
+
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::set_collection_type())]
+		pub fn set_collection_type(
+			origin: OriginFor<T>,
+			s_id: TypeSid,
+			art_type: ArtType,
+		) -> DispatchResult {
+
+			let who = ensure_signed(origin)?;
+			ensure!(Self::is_collection_owner(&who, &s_id), Error::<T, I>::NotAdministrator);
+
+			ensure!(ArtCollection::<T, I>::contains_key(s_id), Error::<T, I>::ArtCollectionNotFound);
+			// The semantics of a collection are fixed once units have been issued.
+			ensure!(NftCount::<T, I>::get(s_id) == 0, Error::<T, I>::ArtCollectionAlreadyIssued);
+
+			let mut old_art_status = ArtStatus::<T, I>::get(s_id);
+			old_art_status.art_type = art_type;
+			ArtStatus::<T, I>::insert(s_id, old_art_status.clone());
+
+			// Emit an event.
+			Self::deposit_event(Event::UpdateArtStatus {
+				s_id,
+				limit: old_art_status.limit,
+				art_type: old_art_status.art_type,
+				locked: old_art_status.locked,
+			});
+			Ok(())
+		}
+
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::transfer_art_ownership())]
+		#[transactional]
+		pub fn transfer_art_ownership(
+			origin: OriginFor<T>,
+			from_bid: BidOf<T, I>,
+			to_bid: BidOf<T, I>,
+			s_id: TypeSid,
+			count: TypeCount,
+		) -> DispatchResult {
+
+			let who = ensure_signed(origin)?;
+			ensure!(Self::is_collection_administrator(&who, &s_id, &AdminRole::Minter), Error::<T, I>::NotAdministrator);
+
+			ensure!(ArtCollection::<T, I>::contains_key(s_id), Error::<T, I>::ArtCollectionNotFound);
+			let art_status = ArtStatus::<T, I>::get(s_id);
+			ensure!(!art_status.locked, Error::<T, I>::ArtCollectionIsLocked);
+			ensure!(art_status.is_transferable(), Error::<T, I>::ArtCollectionNotTransferable);
+			ensure!(count > 0, Error::<T, I>::InvalidCount);
+
+			// Debit the sender then credit the receiver, `NftCount` stays the same.
+			Self::debit_art_ownership(&from_bid, &s_id, count).map_err(Error::<T, I>::from)?;
+			Self::credit_art_ownership(&to_bid, &s_id, count).map_err(Error::<T, I>::from)?;
+
+			// Emit an event.
+			Self::deposit_event(Event::TransferArtOwner { s_id, from_bid, to_bid, count });
+			Ok(())
+		}
+
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::revoke_art_ownership(items.len() as u32))]
+		#[transactional]
+		pub fn revoke_art_ownership(
+			origin: OriginFor<T>,
+			items: MintItemsOf<T, I>,
+			reason: RevokeReason,
+		) -> DispatchResult {
+
+			let who = ensure_signed(origin)?;
+			ensure!(!items.is_empty(), Error::<T, I>::EmptyBatch);
+
+			for (index, item) in items.iter().enumerate() {
+				Self::do_revoke_item(&who, item)
+					.map_err(|error| Error::<T, I>::MintItemFailed { index: index as u16, error })?;
+			}
+			// Get current block number
+			let bn = <frame_system::Pallet<T>>::block_number();
+			// Emit an event.
+			Self::deposit_event(Event::RevokeArtOwner { bn, items, reason });
+			Ok(())
+		}
+
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::grant_role())]
+		pub fn grant_role(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			role: AdminRole,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(
+				Administrators::<T, I>::contains_key(&who) || Administrators::<T, I>::count() < T::MaxAdministrators::get(),
+				Error::<T, I>::TooManyAdministrators
+			);
+
+			// An expired entry that was not pruned yet is replaced, its roles are not revived.
+			if Administrators::<T, I>::contains_key(&who) && !Self::is_active_administrator(&who) {
+				Self::clear_administrator_expiry(&who);
+				Administrators::<T, I>::remove(&who);
+			}
+			Administrators::<T, I>::try_mutate(&who, |roles| -> DispatchResult {
+				ensure!(!roles.contains(&role), Error::<T, I>::RoleAlreadyGranted);
+				roles.insert(&role);
+				Ok(())
+			})?;
+
+			// Emit an event.
+			Self::deposit_event(Event::RoleGranted { who, role });
+			Ok(())
+		}
+
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::revoke_role())]
+		pub fn revoke_role(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			role: AdminRole,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let mut roles = Administrators::<T, I>::get(&who);
+			ensure!(roles.contains(&role), Error::<T, I>::RoleNotGranted);
+			roles.remove(&role);
+			// Accounts without any role are removed from storage.
+			if roles.is_empty() {
+				Administrators::<T, I>::remove(&who);
+				Self::clear_administrator_expiry(&who);
+			} else {
+				Administrators::<T, I>::insert(&who, roles);
+			}
+
+			// Emit an event.
+			Self::deposit_event(Event::RoleRevoked { who, role });
+			Ok(())
+		}
+
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::grant_collection_role())]
+		pub fn grant_collection_role(
+			origin: OriginFor<T>,
+			s_id: TypeSid,
+			who: T::AccountId,
+			role: AdminRole,
+		) -> DispatchResult {
+
+			let owner = ensure_signed(origin)?;
+			ensure!(ArtCollection::<T, I>::contains_key(s_id), Error::<T, I>::ArtCollectionNotFound);
+			ensure!(Self::is_collection_owner(&owner, &s_id), Error::<T, I>::NotAdministrator);
+			ensure!(matches!(role, AdminRole::Minter | AdminRole::MetadataEditor), Error::<T, I>::RoleNotAssignable);
+
+			CollectionAdministrators::<T, I>::try_mutate(s_id, &who, |roles| -> DispatchResult {
+				ensure!(!roles.contains(&role), Error::<T, I>::RoleAlreadyGranted);
+				roles.insert(&role);
+				Ok(())
+			})?;
+
+			// Emit an event.
+			Self::deposit_event(Event::CollectionRoleGranted { s_id, who, role });
+			Ok(())
+		}
+
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::revoke_collection_role())]
+		pub fn revoke_collection_role(
+			origin: OriginFor<T>,
+			s_id: TypeSid,
+			who: T::AccountId,
+			role: AdminRole,
+		) -> DispatchResult {
+
+			let owner = ensure_signed(origin)?;
+			ensure!(ArtCollection::<T, I>::contains_key(s_id), Error::<T, I>::ArtCollectionNotFound);
+			ensure!(Self::is_collection_owner(&owner, &s_id), Error::<T, I>::NotAdministrator);
+
+			let mut roles = CollectionAdministrators::<T, I>::get(s_id, &who);
+			ensure!(roles.contains(&role), Error::<T, I>::RoleNotGranted);
+			roles.remove(&role);
+			if roles.is_empty() {
+				CollectionAdministrators::<T, I>::remove(s_id, &who);
+			} else {
+				CollectionAdministrators::<T, I>::insert(s_id, &who, roles);
+			}
+
+			// Emit an event.
+			Self::deposit_event(Event::CollectionRoleRevoked { s_id, who, role });
+			Ok(())
+		}
+
+		/// Create a collection under an explicit `s_id`, used to import legacy collections.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::force_create_art_collection(name.len() as u32, uri.len() as u32))]
+		pub fn force_create_art_collection(
+			origin: OriginFor<T>,
+			s_id: TypeSid,
+			owner: T::AccountId,
+			name: NameOf<T, I>,
+			uri: UriOf<T, I>
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::do_create_art_collection(owner, s_id, name, uri)
+		}
+
+		/// Like `issue_art_ownership`, but applies the valid items and skips the rejected ones.
+		/// Skipped items are refunded down to the reads spent on validating them.
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::issue_art_ownership_best_effort(items.len() as u32))]
+		pub fn issue_art_ownership_best_effort(
+			origin: OriginFor<T>,
+			items: MintItemsOf<T, I>,
+			request_id: Option<TypeRequestId>,
+		) -> DispatchResultWithPostInfo {
+
+			let who = ensure_signed(origin)?;
+			ensure!(!items.is_empty(), Error::<T, I>::EmptyBatch);
+			if let Some(request_id) = &request_id {
+				Self::ensure_request_new(&who, request_id)?;
+			}
+
+			let mut results = BoundedVec::<Result<(), MintItemError>, T::MaxBatch>::default();
+			for item in items.iter() {
+				// Each item gets its own storage layer so a rejected item leaves nothing behind.
+				let result = with_transaction(|| -> TransactionOutcome<Result<Result<(), MintItemError>, DispatchError>> {
+					let result = Self::do_issue_item(&who, item);
+					if result.is_ok() {
+						TransactionOutcome::Commit(Ok(result))
+					} else {
+						TransactionOutcome::Rollback(Ok(result))
+					}
+				})?;
+				results.try_push(result).map_err(|_| Error::<T, I>::BatchSizeExceeded)?;
+			}
+
+			let issued = results.iter().filter(|result| result.is_ok()).count() as u32;
+			let skipped = results.len() as u32 - issued;
+			// A batch that issued nothing can be retried under the same request id.
+			if let (Some(request_id), true) = (&request_id, issued > 0) {
+				Self::note_request(&who, request_id);
+			}
+			// Get current block number
+			let bn = <frame_system::Pallet<T>>::block_number();
+			// Emit an event.
+			Self::deposit_event(Event::MintArtOwnerBestEffort { bn, items, results, request_id });
+
+			// A skipped item is rolled back, only the reads spent on rejecting it remain.
+			let actual_weight = T::WeightInfo::issue_art_ownership_best_effort(issued)
+				.saturating_add(T::DbWeight::get().reads(SKIPPED_ITEM_READS.saturating_mul(skipped.into())));
+			Ok(Some(actual_weight).into())
+		}
+
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::add_administrator())]
+		pub fn add_administrator(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			role: AdminRole,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(!Self::is_active_administrator(&who), Error::<T, I>::AdministratorAlreadyExists);
+			ensure!(
+				Administrators::<T, I>::contains_key(&who) || Administrators::<T, I>::count() < T::MaxAdministrators::get(),
+				Error::<T, I>::TooManyAdministrators
+			);
+
+			// An expired entry that was not pruned yet is replaced.
+			Self::clear_administrator_expiry(&who);
+			Administrators::<T, I>::insert(&who, AdminRoles(role.flag()));
+
+			// Emit an event.
+			Self::deposit_event(Event::AdministratorAdded { who, role });
+			Ok(())
+		}
+
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::remove_administrator())]
+		pub fn remove_administrator(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(Administrators::<T, I>::contains_key(&who), Error::<T, I>::AdministratorNotFound);
+
+			let roles = Administrators::<T, I>::take(&who);
+			Self::clear_administrator_expiry(&who);
+
+			// Emit an event.
+			Self::deposit_event(Event::AdministratorRemoved { who, roles });
+			Ok(())
+		}
+
+		/// Makes an administrator lose all its global roles at `expires_at`, or never with `None`.
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::set_administrator_expiry())]
+		pub fn set_administrator_expiry(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			expires_at: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(Self::is_active_administrator(&who), Error::<T, I>::AdministratorNotFound);
+			if let Some(expires_at) = expires_at {
+				ensure!(expires_at > <frame_system::Pallet<T>>::block_number(), Error::<T, I>::ExpiryInPast);
+			}
+
+			Self::clear_administrator_expiry(&who);
+			if let Some(expires_at) = expires_at {
+				AdministratorExpiries::<T, I>::insert(&who, expires_at);
+				AdministratorsByExpiry::<T, I>::insert(expires_at, &who, ());
+				NextAdministratorExpiry::<T, I>::mutate(|next| match next {
+					Some(next) if *next <= expires_at => {},
+					_ => *next = Some(expires_at),
+				});
+			}
+
+			// Emit an event.
+			Self::deposit_event(Event::AdministratorExpirySet { who, expires_at });
+			Ok(())
+		}
+
+		/// Sets an attribute of the collection, reserving `AttributeDepositPerByte` for every byte
+		/// of the key and value from the editor. The deposit of a replaced value is returned.
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::set_attribute(key.len() as u32, value.len() as u32))]
+		pub fn set_attribute(
+			origin: OriginFor<T>,
+			s_id: TypeSid,
+			key: AttributeKeyOf<T, I>,
+			value: AttributeValueOf<T, I>,
+		) -> DispatchResult {
+
+			let who = ensure_signed(origin)?;
+			ensure!(Self::is_collection_administrator(&who, &s_id, &AdminRole::MetadataEditor), Error::<T, I>::NotAdministrator);
+			ensure!(ArtCollection::<T, I>::contains_key(s_id), Error::<T, I>::ArtCollectionNotFound);
+
+			let old_attribute = CollectionAttributes::<T, I>::get(s_id, &key);
+			if old_attribute.is_none() {
+				ensure!(AttributeCount::<T, I>::get(s_id) < T::MaxAttributes::get(), Error::<T, I>::TooManyAttributes);
+			}
+
+			let bytes = key.len().saturating_add(value.len()) as u32;
+			let deposit = T::AttributeDepositPerByte::get().saturating_mul(bytes.into());
+			T::Currency::reserve(&who, deposit)?;
+			match old_attribute {
+				Some(old_attribute) => {
+					T::Currency::unreserve(&old_attribute.depositor, old_attribute.deposit);
+				},
+				None => AttributeCount::<T, I>::mutate(s_id, |count| count.saturating_inc()),
+			}
+			CollectionAttributes::<T, I>::insert(s_id, &key, CollectionAttribute {
+				value: value.clone(),
+				depositor: who,
+				deposit,
+			});
+
+			// Emit an event.
+			Self::deposit_event(Event::AttributeSet { s_id, key, value, deposit });
+			Ok(())
+		}
+
+		/// Removes an attribute of the collection and returns its deposit.
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::clear_attribute())]
+		pub fn clear_attribute(
+			origin: OriginFor<T>,
+			s_id: TypeSid,
+			key: AttributeKeyOf<T, I>,
+		) -> DispatchResult {
+
+			let who = ensure_signed(origin)?;
+			ensure!(Self::is_collection_administrator(&who, &s_id, &AdminRole::MetadataEditor), Error::<T, I>::NotAdministrator);
+
+			let attribute = CollectionAttributes::<T, I>::take(s_id, &key).ok_or(Error::<T, I>::AttributeNotFound)?;
+			T::Currency::unreserve(&attribute.depositor, attribute.deposit);
+			AttributeCount::<T, I>::mutate_exists(s_id, |count| {
+				*count = count.map(|count| count.saturating_sub(1)).filter(|count| *count > 0);
+			});
+
+			// Emit an event.
+			Self::deposit_event(Event::AttributeCleared { s_id, key });
+			Ok(())
+		}
+
+		/// Commits the collection to the blake2-256 hash of its artwork file, no other collection
+		/// can register the same file.
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::register_content_hash())]
+		pub fn register_content_hash(
+			origin: OriginFor<T>,
+			s_id: TypeSid,
+			content_hash: TypeContentHash,
+		) -> DispatchResult {
+
+			let who = ensure_signed(origin)?;
+			ensure!(Self::is_collection_administrator(&who, &s_id, &AdminRole::MetadataEditor), Error::<T, I>::NotAdministrator);
+			Self::do_register_content_hash(s_id, content_hash)
+		}
+	}
+
+}
+
+impl<T: Config<I>, I: 'static> From<MintItemError> for Error<T, I> {
+	fn from(error: MintItemError) -> Self {
+		match error {
+			MintItemError::NotAdministrator => Error::<T, I>::NotAdministrator,
+			MintItemError::ArtCollectionNotFound => Error::<T, I>::ArtCollectionNotFound,
+			MintItemError::ArtCollectionIsLocked => Error::<T, I>::ArtCollectionIsLocked,
+			MintItemError::ArtCollectionLimitNotSet => Error::<T, I>::ArtCollectionLimitNotSet,
+			MintItemError::ArtCollectionLimitExceeded => Error::<T, I>::ArtCollectionLimitExceeded,
+			MintItemError::InsufficientOwnership => Error::<T, I>::InsufficientOwnership,
+			MintItemError::StorageOverflow => Error::<T, I>::StorageOverflow,
+			MintItemError::StorageUnderflow => Error::<T, I>::StorageUnderflow,
+		}
 	}
+}
 
+impl<T: Config<I>, I: 'static> From<UriError> for Error<T, I> {
+	fn from(error: UriError) -> Self {
+		match error {
+			UriError::InvalidCharacter => Error::<T, I>::UriInvalidCharacter,
+			UriError::SchemeNotAllowed => Error::<T, I>::UriSchemeNotAllowed,
+			UriError::MissingTarget => Error::<T, I>::UriMissingTarget,
+			UriError::UnsupportedMultibase => Error::<T, I>::CidUnsupportedMultibase,
+			UriError::InvalidCidEncoding => Error::<T, I>::CidInvalidEncoding,
+			UriError::UnsupportedCidVersion => Error::<T, I>::CidUnsupportedVersion,
+			UriError::InvalidMultihash => Error::<T, I>::CidInvalidMultihash,
+		}
+	}
 }
 
-impl<T: Config> Pallet<T> {
-	fn is_administrator_list(who: &T::AccountId, admin_type: &u8) -> bool {
-		if let Some(administrator_list) = AdministratorList::<T>::get()
-		{
-			return administrator_list.contains(&(who.clone(), admin_type.clone()));
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Stores a new collection and keeps `NextCollectionId` ahead of every used id.
+	fn do_create_art_collection(
+		owner: T::AccountId,
+		s_id: TypeSid,
+		name: NameOf<T, I>,
+		uri: UriOf<T, I>,
+	) -> DispatchResult {
+		ensure!(!ArtCollection::<T, I>::contains_key(s_id), Error::<T, I>::ArtCollectionIsExists);
+		let multihash = Self::validate_uri(&uri)?;
+
+		let next_id = s_id.checked_add(1).ok_or(Error::<T, I>::StorageOverflow)?;
+		if next_id > NextCollectionId::<T, I>::get() {
+			NextCollectionId::<T, I>::put(next_id);
+		}
+
+		// Update storage.
+		<ArtCollection<T, I>>::insert(s_id, StructArtCollectionData{
+			name: name.clone(),
+			uri: uri.clone(),
+			multihash,
+			content_hash: None,
+		});
+		<CollectionOwner<T, I>>::insert(s_id, owner.clone());
+
+		// Emit an event.
+		Self::deposit_event(Event::ArtCollectionCreated { s_id, owner, name, uri });
+		Ok(())
+	}
+
+	/// Commits an existing collection without a content hash to `content_hash`.
+	fn do_register_content_hash(s_id: TypeSid, content_hash: TypeContentHash) -> DispatchResult {
+		ensure!(!ContentHashes::<T, I>::contains_key(&content_hash), Error::<T, I>::DuplicateContentHash);
+		ArtCollection::<T, I>::try_mutate(s_id, |data| -> DispatchResult {
+			let data = data.as_mut().ok_or(Error::<T, I>::ArtCollectionNotFound)?;
+			ensure!(data.content_hash.is_none(), Error::<T, I>::ContentHashAlreadySet);
+			data.content_hash = Some(content_hash);
+			Ok(())
+		})?;
+		ContentHashes::<T, I>::insert(&content_hash, s_id);
+
+		Self::deposit_event(Event::ContentHashRegistered { s_id, content_hash });
+		Ok(())
+	}
+
+	/// Checks the uri against `UriSchemes`, returns the multihash of an `ipfs://` uri.
+	fn validate_uri(uri: &UriOf<T, I>) -> Result<Option<Multihash>, Error<T, I>> {
+		crate::uri::validate_uri(uri, T::UriSchemes::get()).map_err(Error::<T, I>::from)
+	}
+
+	/// Expired administrators hold no role, even before `on_idle` prunes them.
+	fn is_administrator_list(who: &T::AccountId, role: &AdminRole) -> bool {
+		Self::is_active_administrator(who) && Administrators::<T, I>::get(who).contains(role)
+	}
+
+	fn is_active_administrator(who: &T::AccountId) -> bool {
+		Administrators::<T, I>::contains_key(who)
+			&& AdministratorExpiries::<T, I>::get(who)
+				.map_or(true, |expires_at| <frame_system::Pallet<T>>::block_number() < expires_at)
+	}
+
+	fn clear_administrator_expiry(who: &T::AccountId) {
+		if let Some(expires_at) = AdministratorExpiries::<T, I>::take(who) {
+			AdministratorsByExpiry::<T, I>::remove(expires_at, who);
+			// `on_idle` only looks for what is left after pruning, drop the cursor here instead.
+			if AdministratorsByExpiry::<T, I>::iter_keys().next().is_none() {
+				NextAdministratorExpiry::<T, I>::kill();
+			}
+		}
+	}
+
+	/// The administrators expiring up to `until`, earliest first.
+	pub fn administrator_expiries(until: BlockNumberFor<T>) -> Vec<(T::AccountId, BlockNumberFor<T>)> {
+		let mut expiries: Vec<_> = AdministratorExpiries::<T, I>::iter()
+			.filter(|(_, expires_at)| *expires_at <= until)
+			.collect();
+		expiries.sort_by_key(|(_, expires_at)| *expires_at);
+		expiries
+	}
+
+	/// Removes the administrators expired by `now`, within `remaining_weight`.
+	fn prune_expired_administrators(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+		let db_weight = T::DbWeight::get();
+		// Reading the cursor.
+		if remaining_weight.any_lt(db_weight.reads(1)) {
+			return Weight::zero();
+		}
+		let mut cursor = match NextAdministratorExpiry::<T, I>::get() {
+			Some(cursor) if cursor <= now => cursor,
+			_ => return db_weight.reads(1),
+		};
+		// Writing back the cursor and looking for what is left once something was pruned.
+		let mut used = db_weight.reads_writes(2, 1);
+		let mut pruned = false;
+
+		// Draining the next account of a block and removing its roles and expiry. A block
+		// with nothing left to drain costs the read that found it empty.
+		let per_administrator = T::WeightInfo::prune_expired_administrator();
+		while cursor <= now {
+			let mut expired = AdministratorsByExpiry::<T, I>::drain_prefix(cursor);
+			loop {
+				if remaining_weight.any_lt(used.saturating_add(per_administrator)) {
+					NextAdministratorExpiry::<T, I>::put(cursor);
+					return used;
+				}
+				match expired.next() {
+					Some((who, ())) => {
+						used.saturating_accrue(per_administrator);
+						pruned = true;
+						Self::remove_expired_administrator(who);
+					},
+					None => {
+						used.saturating_accrue(db_weight.reads(1));
+						break;
+					},
+				}
+			}
+			cursor.saturating_inc();
+		}
+
+		// Removals outside `on_idle` drop the cursor themselves, stop walking blocks once pruning
+		// left nothing pending.
+		if pruned && AdministratorsByExpiry::<T, I>::iter_keys().next().is_none() {
+			NextAdministratorExpiry::<T, I>::kill();
+		} else {
+			NextAdministratorExpiry::<T, I>::put(cursor);
+		}
+		used
+	}
+
+	/// Removes an administrator drained from `AdministratorsByExpiry`.
+	fn remove_expired_administrator(who: T::AccountId) {
+		AdministratorExpiries::<T, I>::remove(&who);
+		let roles = Administrators::<T, I>::take(&who);
+		Self::deposit_event(Event::AdministratorExpired { who, roles });
+	}
+
+	/// The owner manages its own collection, collections created before owners were recorded
+	/// stay with the global creators. Ids without a collection have no owner.
+	fn is_collection_owner(who: &T::AccountId, s_id: &TypeSid) -> bool {
+		match CollectionOwner::<T, I>::get(s_id) {
+			Some(owner) => &owner == who,
+			None => ArtCollection::<T, I>::contains_key(s_id) && Self::is_administrator_list(who, &AdminRole::Creator),
+		}
+	}
+
+	/// Whether `who` can act as `role` on the collection, through its owner, a role scoped to
+	/// the collection or a global role other than creator.
+	fn is_collection_administrator(who: &T::AccountId, s_id: &TypeSid, role: &AdminRole) -> bool {
+		Self::is_collection_owner(who, s_id)
+			|| (role != &AdminRole::Creator && Self::is_administrator_list(who, role))
+			|| CollectionAdministrators::<T, I>::get(s_id, who).contains(role)
+	}
+
+	/// Issues a single item of a batch, the caller rolls the batch back on error.
+	fn do_issue_item(who: &T::AccountId, item: &MintItemOf<T, I>) -> Result<(), MintItemError> {
+		ensure!(Self::is_collection_administrator(who, &item.s_id, &AdminRole::Minter), MintItemError::NotAdministrator);
+		ensure!(ArtCollection::<T, I>::contains_key(item.s_id), MintItemError::ArtCollectionNotFound);
+		// Check ArtStatus locked status
+		let art_status = ArtStatus::<T, I>::get(item.s_id);
+		ensure!(!art_status.locked, MintItemError::ArtCollectionIsLocked);
+
+		Self::mint_units(item, &art_status)
+	}
+
+	/// Adds `item.count` units to the supply of the collection within its limit and credits them.
+	fn mint_units(item: &MintItemOf<T, I>, art_status: &StructArtStatus) -> Result<(), MintItemError> {
+		let new_count = NftCount::<T, I>::get(item.s_id).checked_add(item.count).ok_or(MintItemError::StorageOverflow)?;
+		// Check the supply limit of the collection
+		ensure!(
+			art_status.art_type != ArtType::LimitedEdition || art_status.limit.is_some(),
+			MintItemError::ArtCollectionLimitNotSet
+		);
+		if let Some(limit) = art_status.max_supply() {
+			ensure!(new_count <= limit, MintItemError::ArtCollectionLimitExceeded);
+		}
+		NftCount::<T, I>::insert(item.s_id, new_count);
+
+		Self::credit_art_ownership(&item.b_id, &item.s_id, item.count)
+	}
+
+	/// Revokes a single item of a batch, the caller rolls the batch back on error.
+	fn do_revoke_item(who: &T::AccountId, item: &MintItemOf<T, I>) -> Result<(), MintItemError> {
+		ensure!(Self::is_collection_administrator(who, &item.s_id, &AdminRole::Minter), MintItemError::NotAdministrator);
+		ensure!(ArtCollection::<T, I>::contains_key(item.s_id), MintItemError::ArtCollectionNotFound);
+		// Check ArtStatus locked status
+		ensure!(!ArtStatus::<T, I>::get(item.s_id).locked, MintItemError::ArtCollectionIsLocked);
+
+		Self::burn_units(item)
+	}
+
+	/// Takes `item.count` units back from `item.b_id` and out of the supply of the collection.
+	fn burn_units(item: &MintItemOf<T, I>) -> Result<(), MintItemError> {
+		Self::debit_art_ownership(&item.b_id, &item.s_id, item.count)?;
+
+		let new_count = NftCount::<T, I>::get(item.s_id).checked_sub(item.count).ok_or(MintItemError::StorageUnderflow)?;
+		if new_count == 0 {
+			NftCount::<T, I>::remove(item.s_id);
+		} else {
+			NftCount::<T, I>::insert(item.s_id, new_count);
+		}
+		Ok(())
+	}
+
+	/// Adds `count` units of `s_id` to `b_id` and keeps `NftHolders` in sync.
+	fn credit_art_ownership(b_id: &BidOf<T, I>, s_id: &TypeSid, count: TypeCount) -> Result<(), MintItemError> {
+		if count == 0 {
+			return Ok(());
+		}
+		let new_count = NftBindInfos::<T, I>::get(b_id, s_id).checked_add(count).ok_or(MintItemError::StorageOverflow)?;
+		NftBindInfos::<T, I>::insert(b_id, s_id, new_count);
+		NftHolders::<T, I>::insert(s_id, b_id, ());
+		Ok(())
+	}
+
+	/// Takes `count` units of `s_id` from `b_id`, the entry is removed once it reaches zero.
+	fn debit_art_ownership(b_id: &BidOf<T, I>, s_id: &TypeSid, count: TypeCount) -> Result<(), MintItemError> {
+		let new_count = NftBindInfos::<T, I>::get(b_id, s_id).checked_sub(count).ok_or(MintItemError::InsufficientOwnership)?;
+		if new_count == 0 {
+			NftBindInfos::<T, I>::remove(b_id, s_id);
+			NftHolders::<T, I>::remove(s_id, b_id);
+		} else {
+			NftBindInfos::<T, I>::insert(b_id, s_id, new_count);
+		}
+		Ok(())
+	}
+
+	/// The collections held by a relation id, with their count.
+	pub fn collections_of(b_id: &BidOf<T, I>) -> Vec<(TypeSid, TypeCount)> {
+		NftBindInfos::<T, I>::iter_prefix(b_id).collect()
+	}
+
+	/// The relation ids holding a collection, with their count.
+	pub fn holders_of(s_id: &TypeSid) -> Vec<(BidOf<T, I>, TypeCount)> {
+		NftHolders::<T, I>::iter_key_prefix(s_id)
+			.map(|b_id| {
+				let count = NftBindInfos::<T, I>::get(&b_id, s_id);
+				(b_id, count)
+			})
+			.collect()
+	}
+
+	/// A page of `holders_of`, at most `limit` holders after `start_after` in storage order.
+	pub fn holders_of_paged(s_id: &TypeSid, start_after: Option<BidOf<T, I>>, limit: u32) -> Vec<(BidOf<T, I>, TypeCount)> {
+		let holders = match start_after {
+			Some(b_id) => NftHolders::<T, I>::iter_key_prefix_from(s_id, NftHolders::<T, I>::hashed_key_for(s_id, b_id)),
+			None => NftHolders::<T, I>::iter_key_prefix(s_id),
+		};
+		holders
+			.take(limit as usize)
+			.map(|b_id| {
+				let count = NftBindInfos::<T, I>::get(&b_id, s_id);
+				(b_id, count)
+			})
+			.collect()
+	}
+
+	/// Everything known about a collection, `None` if it does not exist.
+	pub fn collection_info(s_id: &TypeSid) -> Option<CollectionInfo<T::AccountId>> {
+		ArtCollection::<T, I>::get(s_id).map(|data| CollectionInfo {
+			data: StructArtCollectionData {
+				name: data.name.into_inner(),
+				uri: data.uri.into_inner(),
+				multihash: data.multihash,
+				content_hash: data.content_hash,
+			},
+			status: ArtStatus::<T, I>::get(s_id),
+			owner: CollectionOwner::<T, I>::get(s_id),
+			total_supply: NftCount::<T, I>::get(s_id),
+		})
+	}
+
+	/// The collection committed to `content_hash`, if any.
+	pub fn collection_of_content_hash(content_hash: &TypeContentHash) -> Option<TypeSid> {
+		ContentHashes::<T, I>::get(content_hash)
+	}
+
+	/// Whether the collection is committed to the artwork file with `content_hash`.
+	pub fn verify_content_hash(s_id: &TypeSid, content_hash: &TypeContentHash) -> bool {
+		ContentHashes::<T, I>::get(content_hash) == Some(*s_id)
+	}
+
+	/// Whether a mint batch of `who` with `request_id` was processed and has not expired yet.
+	pub fn is_request_processed(who: &T::AccountId, request_id: &TypeRequestId) -> bool {
+		ProcessedRequests::<T, I>::contains_key(who, request_id)
+	}
+
+	/// Fails if `who` already used `request_id` within the last `RequestIdExpiry` blocks.
+	fn ensure_request_new(who: &T::AccountId, request_id: &TypeRequestId) -> DispatchResult {
+		ensure!(!Self::is_request_processed(who, request_id), Error::<T, I>::RequestAlreadyProcessed);
+		Ok(())
+	}
+
+	/// Records `request_id` of `who` as processed, it expires after `RequestIdExpiry` blocks.
+	fn note_request(who: &T::AccountId, request_id: &TypeRequestId) {
+		let now = <frame_system::Pallet<T>>::block_number();
+		let expiry = now.saturating_add(T::RequestIdExpiry::get());
+		ProcessedRequests::<T, I>::insert(who, request_id, now);
+		RequestExpiries::<T, I>::insert(expiry, (who, request_id), ());
+		// The expiry may have shortened in an upgrade, keep the cursor on the earliest block.
+		NextRequestExpiry::<T, I>::mutate(|next| match next {
+			Some(next) if *next <= expiry => {},
+			_ => *next = Some(expiry),
+		});
+	}
+
+	/// Removes the request ids expired by `now`, within `remaining_weight`.
+	fn prune_processed_requests(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+		let db_weight = T::DbWeight::get();
+		// Reading the cursor.
+		if remaining_weight.any_lt(db_weight.reads(1)) {
+			return Weight::zero();
+		}
+		let mut cursor = match NextRequestExpiry::<T, I>::get() {
+			Some(cursor) if cursor <= now => cursor,
+			_ => return db_weight.reads(1),
+		};
+		// Writing back the cursor and looking for what is left once something was pruned.
+		let mut used = db_weight.reads_writes(2, 1);
+		let mut pruned = false;
+
+		// One read to find the next id of a block, two writes to remove it. A block with
+		// nothing left to drain costs the read that found it empty.
+		let per_request = db_weight.reads_writes(1, 2);
+		while cursor <= now {
+			let mut expired = RequestExpiries::<T, I>::drain_prefix(cursor);
+			loop {
+				if remaining_weight.any_lt(used.saturating_add(per_request)) {
+					NextRequestExpiry::<T, I>::put(cursor);
+					return used;
+				}
+				match expired.next() {
+					Some(((who, request_id), ())) => {
+						used.saturating_accrue(per_request);
+						pruned = true;
+						ProcessedRequests::<T, I>::remove(who, request_id);
+					},
+					None => {
+						used.saturating_accrue(db_weight.reads(1));
+						break;
+					},
+				}
+			}
+			cursor.saturating_inc();
+		}
+
+		// Ids are only removed here, stop walking blocks once nothing is pending.
+		if pruned && RequestExpiries::<T, I>::iter_keys().next().is_none() {
+			NextRequestExpiry::<T, I>::kill();
 		} else {
-			return false;
+			NextRequestExpiry::<T, I>::put(cursor);
 		}
+		used
 	}
 }
@@ -0,0 +1,397 @@
+//! Storage migrations for pallet-eternal-arts.
+//!
+//! Every migration is generic over the pallet instance and only touches the storage of the
+//! instance it is given, so a runtime with several instances lists it once per instance,
+//! e.g. `(v8::MigrateToV8<Runtime>, v8::MigrateToV8<Runtime, Instance1>)`.
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+use sp_std::vec::Vec;
+
+const LOG_TARGET: &str = "runtime::eternal-arts";
+
+pub mod v1 {
+	use super::*;
+
+	/// `StructArtStatus` as stored before version 1, where a `limit` of `0` was never enforced.
+	/// `art_type` was a bare `u8`, which shares its encoding with `ArtType`.
+	#[derive(Encode, Decode)]
+	pub struct OldStructArtStatus {
+		pub limit: u64,
+		pub art_type: ArtType,
+		pub locked: bool,
+	}
+
+	/// Turns `StructArtStatus.limit` into an `Option`, old entries with `0` become unlimited.
+	pub struct MigrateToV1<T, I = ()>(sp_std::marker::PhantomData<(T, I)>);
+
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV1<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T, I>::on_chain_storage_version() != 0 {
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut translated = 0u64;
+			ArtStatus::<T, I>::translate::<OldStructArtStatus, _>(|_s_id, old| {
+				translated = translated.saturating_add(1);
+				Some(StructArtStatus {
+					limit: if old.limit == 0 { None } else { Some(old.limit) },
+					art_type: old.art_type,
+					locked: old.locked,
+				})
+			});
+			StorageVersion::new(1).put::<Pallet<T, I>>();
+
+			T::DbWeight::get().reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+	}
+}
+
+pub mod v2 {
+	use super::*;
+
+	/// The administrator list as stored before version 2.
+	#[frame_support::storage_alias]
+	pub type AdministratorList<T: Config<I>, I: 'static> =
+		StorageValue<Pallet<T, I>, Vec<(<T as frame_system::Config>::AccountId, u8)>, OptionQuery>;
+
+	/// Moves `AdministratorList` into the `Administrators` role map.
+	///
+	/// The old admin types `0` and `1` become `AdminRole::Creator` and `AdminRole::Minter`,
+	/// any other value was never checked by the pallet and is dropped.
+	pub struct MigrateToV2<T, I = ()>(sp_std::marker::PhantomData<(T, I)>);
+
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV2<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T, I>::on_chain_storage_version() != 1 {
+				return T::DbWeight::get().reads(1);
+			}
+
+			let administrator_list = AdministratorList::<T, I>::take().unwrap_or_default();
+			let mut writes = 2u64;
+			for (who, admin_type) in administrator_list.iter() {
+				let role = match admin_type {
+					0 => AdminRole::Creator,
+					1 => AdminRole::Minter,
+					_ => continue,
+				};
+				Administrators::<T, I>::mutate(who, |roles| roles.insert(&role));
+				writes = writes.saturating_add(1);
+			}
+			StorageVersion::new(2).put::<Pallet<T, I>>();
+
+			T::DbWeight::get().reads_writes(writes, writes)
+		}
+	}
+}
+
+pub mod v3 {
+	use super::*;
+
+	/// The relation id bound until version 5, which shares its encoding with `BidOf`.
+	pub type TypeBid = BoundedVec<u8, ConstU32<100>>;
+
+	/// `NftBindInfos` as stored before version 3, keyed by the `(b_id, s_id)` tuple.
+	#[frame_support::storage_alias]
+	pub type NftBindInfos<T: Config<I>, I: 'static> =
+		StorageMap<Pallet<T, I>, Twox64Concat, (TypeBid, TypeSid), TypeCount, ValueQuery>;
+
+	/// The version 3 layout of `NftBindInfos` and `NftHolders`.
+	pub mod new {
+		use super::*;
+
+		#[frame_support::storage_alias]
+		pub type NftBindInfos<T: Config<I>, I: 'static> =
+			StorageDoubleMap<Pallet<T, I>, Blake2_128Concat, TypeBid, Twox64Concat, TypeSid, TypeCount, ValueQuery>;
+
+		#[frame_support::storage_alias]
+		pub type NftHolders<T: Config<I>, I: 'static> =
+			StorageDoubleMap<Pallet<T, I>, Twox64Concat, TypeSid, Blake2_128Concat, TypeBid, (), OptionQuery>;
+	}
+
+	/// Moves `NftBindInfos` to the double map layout and builds the `NftHolders` index.
+	pub struct MigrateToV3<T, I = ()>(sp_std::marker::PhantomData<(T, I)>);
+
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV3<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T, I>::on_chain_storage_version() != 2 {
+				return T::DbWeight::get().reads(1);
+			}
+
+			// The new map shares the storage prefix, so the old entries are taken out first.
+			let old_bind_infos: Vec<((TypeBid, TypeSid), TypeCount)> = NftBindInfos::<T, I>::drain().collect();
+			let migrated = old_bind_infos.len() as u64;
+			for ((b_id, s_id), count) in old_bind_infos {
+				if count == 0 {
+					continue;
+				}
+				new::NftBindInfos::<T, I>::insert(&b_id, &s_id, count);
+				new::NftHolders::<T, I>::insert(&s_id, &b_id, ());
+			}
+			StorageVersion::new(3).put::<Pallet<T, I>>();
+
+			T::DbWeight::get().reads_writes(
+				migrated.saturating_add(1),
+				migrated.saturating_mul(3).saturating_add(1),
+			)
+		}
+	}
+}
+
+pub mod v4 {
+	use super::*;
+
+	/// Counts the existing `Administrators`, which became a counted map in version 4.
+	pub struct MigrateToV4<T, I = ()>(sp_std::marker::PhantomData<(T, I)>);
+
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV4<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T, I>::on_chain_storage_version() != 3 {
+				return T::DbWeight::get().reads(1);
+			}
+
+			// Above `MaxAdministrators` no administrator can be added until enough are removed.
+			let counted = Administrators::<T, I>::initialize_counter();
+			StorageVersion::new(4).put::<Pallet<T, I>>();
+
+			T::DbWeight::get().reads_writes((counted as u64).saturating_add(1), 2)
+		}
+	}
+}
+
+pub mod v5 {
+	use super::*;
+
+	/// `StructArtCollectionData` as stored before version 5, bound to 100 bytes. Bounded vectors
+	/// share their encoding with `Vec`.
+	#[derive(Encode, Decode)]
+	pub struct OldStructArtCollectionData {
+		pub name: Vec<u8>,
+		pub uri: Vec<u8>,
+	}
+
+	/// `StructArtCollectionData` as stored in version 5.
+	#[derive(Encode, Decode)]
+	pub struct StructArtCollectionData<Name, Uri> {
+		pub name: Name,
+		pub uri: Uri,
+	}
+
+	/// `ArtCollection` in its version 5 layout.
+	#[frame_support::storage_alias]
+	pub type ArtCollection<T: Config<I>, I: 'static> =
+		StorageMap<Pallet<T, I>, Twox64Concat, u64, StructArtCollectionData<NameOf<T, I>, UriOf<T, I>>, OptionQuery>;
+
+	/// `ArtCollection` as stored before version 5.
+	#[frame_support::storage_alias]
+	pub type OldArtCollection<T: Config<I>, I: 'static> =
+		StorageMap<Pallet<T, I>, Twox64Concat, u64, OldStructArtCollectionData, OptionQuery>;
+
+	/// The stored names, uris and relation ids longer than the bounds of version 5.
+	#[derive(Encode, Decode, Default, PartialEq, RuntimeDebug)]
+	pub struct OverLongEntries {
+		pub names: u32,
+		pub uris: u32,
+		pub bids: u32,
+	}
+
+	impl OverLongEntries {
+		/// Counts the over-long entries, with the number of storage reads it took.
+		pub fn count<T: Config<I>, I: 'static>() -> (Self, u64) {
+			let mut over_long = Self::default();
+			let mut reads = 0u64;
+			for data in OldArtCollection::<T, I>::iter_values() {
+				reads = reads.saturating_add(1);
+				if data.name.len() > T::MaxNameLength::get() as usize {
+					over_long.names = over_long.names.saturating_add(1);
+				}
+				if data.uri.len() > T::MaxUriLength::get() as usize {
+					over_long.uris = over_long.uris.saturating_add(1);
+				}
+			}
+			// `NftHolders` holds the same relation ids.
+			for (b_id, _s_id) in v3::new::NftBindInfos::<T, I>::iter_keys() {
+				reads = reads.saturating_add(1);
+				if b_id.len() > T::MaxBidLength::get() as usize {
+					over_long.bids = over_long.bids.saturating_add(1);
+				}
+			}
+			(over_long, reads)
+		}
+
+		pub fn is_empty(&self) -> bool {
+			self == &Self::default()
+		}
+	}
+
+	/// Re-bounds the collection data to `MaxNameLength` and `MaxUriLength`.
+	///
+	/// Nothing is truncated: relation ids are keys of `NftBindInfos` and `NftHolders` and would
+	/// no longer decode under a shorter `MaxBidLength`. If any stored name, uri or relation id
+	/// is longer than its new bound, the upgrade panics. Leaving the storage at version 4 would
+	/// skip the later migrations and the runtime could not decode any collection. `pre_upgrade`
+	/// fails on the same entries, so try-runtime catches such a runtime before it ships.
+	pub struct MigrateToV5<T, I = ()>(sp_std::marker::PhantomData<(T, I)>);
+
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV5<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T, I>::on_chain_storage_version() != 4 {
+				return T::DbWeight::get().reads(1);
+			}
+
+			let (over_long, reads) = OverLongEntries::count::<T, I>();
+			if !over_long.is_empty() {
+				log::error!(
+					target: LOG_TARGET,
+					"MigrateToV5 refused, entries longer than the new bounds: {:?}",
+					over_long,
+				);
+				panic!("MigrateToV5: stored entries are longer than the bounds of version 5");
+			}
+
+			let mut translated = 0u64;
+			ArtCollection::<T, I>::translate::<OldStructArtCollectionData, _>(|_s_id, old| {
+				translated = translated.saturating_add(1);
+				// Every entry was checked against the bounds above.
+				Some(StructArtCollectionData {
+					name: old.name.try_into().ok()?,
+					uri: old.uri.try_into().ok()?,
+				})
+			});
+			StorageVersion::new(5).put::<Pallet<T, I>>();
+
+			T::DbWeight::get().reads_writes(
+				reads.saturating_add(translated).saturating_add(1),
+				translated.saturating_add(1),
+			)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+			let (over_long, _) = OverLongEntries::count::<T, I>();
+			ensure!(over_long.is_empty(), "entries are longer than the bounds of version 5");
+			Ok((OldArtCollection::<T, I>::iter_keys().count() as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+			let collections = u32::decode(&mut &state[..]).map_err(|_| "invalid pre_upgrade state")?;
+			ensure!(Pallet::<T, I>::on_chain_storage_version() == 5, "storage version was not updated");
+			ensure!(ArtCollection::<T, I>::iter_values().count() as u32 == collections, "collections were lost");
+			Ok(())
+		}
+	}
+}
+
+pub mod v6 {
+	use super::*;
+
+	/// `StructArtCollectionData` as stored in version 6.
+	#[derive(Encode, Decode)]
+	pub struct StructArtCollectionData<Name, Uri> {
+		pub name: Name,
+		pub uri: Uri,
+		pub multihash: Option<Multihash>,
+	}
+
+	/// `ArtCollection` in its version 6 layout.
+	#[frame_support::storage_alias]
+	pub type ArtCollection<T: Config<I>, I: 'static> =
+		StorageMap<Pallet<T, I>, Twox64Concat, u64, StructArtCollectionData<NameOf<T, I>, UriOf<T, I>>, OptionQuery>;
+
+	/// Stores the multihash of the `ipfs://` uris of the existing collections.
+	///
+	/// Existing uris are not checked against `UriSchemes`, a CID that can not be decoded leaves
+	/// the multihash empty.
+	pub struct MigrateToV6<T, I = ()>(sp_std::marker::PhantomData<(T, I)>);
+
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV6<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T, I>::on_chain_storage_version() != 5 {
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut translated = 0u64;
+			ArtCollection::<T, I>::translate::<v5::StructArtCollectionData<NameOf<T, I>, UriOf<T, I>>, _>(|_s_id, old| {
+				translated = translated.saturating_add(1);
+				let multihash = old
+					.uri
+					.strip_prefix(uri::IPFS_SCHEME)
+					.and_then(|target| uri::decode_ipfs_target(target).ok());
+				Some(StructArtCollectionData { name: old.name, uri: old.uri, multihash })
+			});
+			StorageVersion::new(6).put::<Pallet<T, I>>();
+
+			T::DbWeight::get().reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+	}
+}
+
+pub mod v7 {
+	use super::*;
+
+	/// Adds an empty content hash to the existing collections, their owners register it with
+	/// `register_content_hash`.
+	pub struct MigrateToV7<T, I = ()>(sp_std::marker::PhantomData<(T, I)>);
+
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV7<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T, I>::on_chain_storage_version() != 6 {
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut translated = 0u64;
+			ArtCollection::<T, I>::translate::<v6::StructArtCollectionData<NameOf<T, I>, UriOf<T, I>>, _>(|_s_id, old| {
+				translated = translated.saturating_add(1);
+				Some(StructArtCollectionData {
+					name: old.name,
+					uri: old.uri,
+					multihash: old.multihash,
+					content_hash: None,
+				})
+			});
+			StorageVersion::new(7).put::<Pallet<T, I>>();
+
+			T::DbWeight::get().reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+	}
+}
+
+pub mod v8 {
+	use super::*;
+
+	/// Moves `NextCollectionId` past the highest existing collection id.
+	///
+	/// Before ids were allocated, callers picked them, usually starting at `0`. Without this
+	/// the allocator would start on a taken id and `create_art_collection` would keep failing.
+	pub struct MigrateToV8<T, I = ()>(sp_std::marker::PhantomData<(T, I)>);
+
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV8<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T, I>::on_chain_storage_version() != 7 {
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut reads = 2u64;
+			let mut next_id = NextCollectionId::<T, I>::get();
+			for s_id in ArtCollection::<T, I>::iter_keys() {
+				reads = reads.saturating_add(1);
+				next_id = next_id.max(s_id.saturating_add(1));
+			}
+			NextCollectionId::<T, I>::put(next_id);
+			StorageVersion::new(8).put::<Pallet<T, I>>();
+
+			T::DbWeight::get().reads_writes(reads, 2)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+			ensure!(Pallet::<T, I>::on_chain_storage_version() == 8, "storage version was not updated");
+			let next_id = NextCollectionId::<T, I>::get();
+			ensure!(ArtCollection::<T, I>::iter_keys().all(|s_id| s_id < next_id), "an existing id is not allocated yet");
+			Ok(())
+		}
+	}
+}
@@ -1,9 +1,14 @@
 use crate as pallet_eternal_arts;
-use frame_support::traits::{ConstU16, ConstU64, ConstU32, ConstU8};
+use frame_support::instances::Instance1;
+use frame_support::traits::{ConstU16, ConstU64, ConstU32};
 use sp_core::H256;
 use sp_runtime::{traits::{BlakeTwo256, IdentityLookup}, BuildStorage, BoundedVec};
 
-pub type DebugMaxBachSize = ConstU8<5>;
+pub type DebugMaxBachSize = ConstU32<5>;
+
+frame_support::parameter_types! {
+	pub const UriSchemes: &'static [&'static [u8]] = &[b"ipfs://", b"ar://", b"https://"];
+}
 
 
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -13,7 +18,9 @@ frame_support::construct_runtime!(
 	pub enum Test
 	{
 		System: frame_system,
+		Balances: pallet_balances,
 		EternalArtsModule: pallet_eternal_arts,
+		EternalArtsMuseum: pallet_eternal_arts::<Instance1>,
 	}
 );
 
@@ -34,7 +41,7 @@ impl frame_system::Config for Test {
 	type BlockHashCount = ConstU64<250>;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u64>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -43,9 +50,55 @@ impl frame_system::Config for Test {
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type RuntimeHoldReason = ();
+	type MaxHolds = ();
+}
+
 impl pallet_eternal_arts::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
-	type BatchMintSize = DebugMaxBachSize;
+	type MaxBatch = DebugMaxBachSize;
+	type RequestIdExpiry = ConstU64<10>;
+	type AdminOrigin = frame_system::EnsureRoot<u64>;
+	type MaxAdministrators = ConstU32<3>;
+	type Currency = Balances;
+	type AttributeDepositPerByte = ConstU64<1>;
+	type MaxAttributeKeyLength = ConstU32<16>;
+	type MaxAttributeValueLength = ConstU32<32>;
+	type MaxAttributes = ConstU32<2>;
+	type MaxNameLength = ConstU32<100>;
+	type MaxUriLength = ConstU32<100>;
+	type MaxBidLength = ConstU32<100>;
+	type UriSchemes = UriSchemes;
+	type WeightInfo = ();
+}
+
+impl pallet_eternal_arts::Config<Instance1> for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type MaxBatch = DebugMaxBachSize;
+	type RequestIdExpiry = ConstU64<10>;
+	type AdminOrigin = frame_system::EnsureRoot<u64>;
+	type MaxAdministrators = ConstU32<3>;
+	type Currency = Balances;
+	type AttributeDepositPerByte = ConstU64<1>;
+	type MaxAttributeKeyLength = ConstU32<16>;
+	type MaxAttributeValueLength = ConstU32<32>;
+	type MaxAttributes = ConstU32<2>;
+	type MaxNameLength = ConstU32<100>;
+	type MaxUriLength = ConstU32<100>;
+	type MaxBidLength = ConstU32<100>;
+	type UriSchemes = UriSchemes;
 	type WeightInfo = ();
 }
 
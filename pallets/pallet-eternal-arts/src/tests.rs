@@ -1,5 +1,6 @@
 use crate::{mock::*, Error, Event, Pallet, pallet::*};
 use frame_support::{assert_noop, assert_ok};
+use frame_support::traits::Get;
 use sp_runtime::app_crypto::sp_core::ConstU32;
 use sp_runtime::BoundedVec;
 use bound_vec_helper::BoundVecHelper;
@@ -10,11 +11,239 @@ fn it_works_for_update_administrator_list() {
     new_test_ext().execute_with(|| {
         // Go past genesis block so events get deposited
         System::set_block_number(1);
-        let administrators = vec![(1, ADMIN_TYPE_IS_MINTER), (2, ADMIN_TYPE_IS_CREATOR), (3, ADMIN_TYPE_IS_CREATOR)];
+        let administrators = vec![(1, AdminRole::Minter), (2, AdminRole::Creator), (3, AdminRole::Locker)];
         handler_set_admin_list(administrators.clone());
 
-        assert_eq!(AdministratorList::<Test>::get(), Some(administrators.clone()));
-        System::assert_last_event(Event::UpdateAdministratorList { administrator_list: administrators }.into());
+        assert!(Administrators::<Test>::get(1).contains(&AdminRole::Minter));
+        assert!(!Administrators::<Test>::get(1).contains(&AdminRole::Creator));
+        assert!(Administrators::<Test>::get(2).contains(&AdminRole::Creator));
+        assert!(Administrators::<Test>::get(3).contains(&AdminRole::Locker));
+        System::assert_last_event(Event::UpdateAdministratorList {
+            administrator_list: BoundedVec::create_on_vec(administrators),
+        }.into());
+
+        // The whole list is replaced.
+        handler_set_admin_list(vec![(4, AdminRole::Minter)]);
+        assert!(!Administrators::<Test>::contains_key(1));
+        assert!(Administrators::<Test>::get(4).contains(&AdminRole::Minter));
+
+        // Expiries are cleared with the administrators they belong to.
+        assert_ok!(EternalArtsModule::set_administrator_expiry(RuntimeOrigin::root(), 4, Some(10)));
+        handler_set_admin_list(vec![(5, AdminRole::Minter)]);
+        assert!(!AdministratorExpiries::<Test>::contains_key(4));
+        assert_eq!(AdministratorsByExpiry::<Test>::iter_keys().count(), 0);
+        assert_eq!(NextAdministratorExpiry::<Test>::get(), None);
+
+        // The call is charged for clearing `MaxAdministrators` entries, it refuses to clear more.
+        for who in 6..9 {
+            Administrators::<Test>::insert(who, AdminRoles(AdminRole::Locker.flag()));
+        }
+        assert_noop!(
+            EternalArtsModule::update_administrator_list(RuntimeOrigin::root(), BoundedVec::create_on_vec(vec![(1, AdminRole::Minter)])),
+            Error::<Test>::TooManyAdministrators
+        );
+    });
+}
+
+#[test]
+fn it_works_for_grant_and_revoke_role() {
+    new_test_ext().execute_with(|| {
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
+
+        assert_noop!(
+            EternalArtsModule::grant_role(RuntimeOrigin::signed(1), 1, AdminRole::Locker),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_ok!(EternalArtsModule::grant_role(RuntimeOrigin::root(), 1, AdminRole::Locker));
+        System::assert_last_event(Event::RoleGranted { who: 1, role: AdminRole::Locker }.into());
+        assert_ok!(EternalArtsModule::grant_role(RuntimeOrigin::root(), 1, AdminRole::MetadataEditor));
+        assert_noop!(
+            EternalArtsModule::grant_role(RuntimeOrigin::root(), 1, AdminRole::Locker),
+            Error::<Test>::RoleAlreadyGranted
+        );
+        assert_eq!(Administrators::<Test>::get(1), AdminRoles(AdminRole::Locker.flag() | AdminRole::MetadataEditor.flag()));
+
+        // A locker can lock but not create.
+        let art_name = NameOf::<Test>::create_on_vec(b"testName".to_vec());
+        let art_url = UriOf::<Test>::create_on_vec(b"https://abc.json".to_vec());
+        assert_noop!(
+            EternalArtsModule::create_art_collection(RuntimeOrigin::signed(1), art_name.clone(), art_url.clone()),
+            Error::<Test>::NotAdministrator
+        );
+        assert_ok!(EternalArtsModule::grant_role(RuntimeOrigin::root(), 2, AdminRole::Creator));
+        assert_ok!(EternalArtsModule::create_art_collection(RuntimeOrigin::signed(2), art_name.clone(), art_url.clone()));
+        assert_ok!(EternalArtsModule::set_collection_status(RuntimeOrigin::signed(1), 0, true));
+        assert_ok!(EternalArtsModule::update_art_collection(RuntimeOrigin::signed(1), 0, art_name.clone(), art_url.clone()));
+
+        assert_ok!(EternalArtsModule::revoke_role(RuntimeOrigin::root(), 1, AdminRole::Locker));
+        System::assert_last_event(Event::RoleRevoked { who: 1, role: AdminRole::Locker }.into());
+        assert_noop!(
+            EternalArtsModule::revoke_role(RuntimeOrigin::root(), 1, AdminRole::Locker),
+            Error::<Test>::RoleNotGranted
+        );
+        assert_noop!(
+            EternalArtsModule::set_collection_status(RuntimeOrigin::signed(1), 0, false),
+            Error::<Test>::NotAdministrator
+        );
+
+        // Accounts without roles leave the storage.
+        assert_ok!(EternalArtsModule::revoke_role(RuntimeOrigin::root(), 1, AdminRole::MetadataEditor));
+        assert!(!Administrators::<Test>::contains_key(1));
+    });
+}
+
+#[test]
+fn it_works_for_add_and_remove_administrator() {
+    new_test_ext().execute_with(|| {
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
+
+        assert_noop!(
+            EternalArtsModule::add_administrator(RuntimeOrigin::signed(1), 1, AdminRole::Creator),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_ok!(EternalArtsModule::add_administrator(RuntimeOrigin::root(), 1, AdminRole::Creator));
+        System::assert_last_event(Event::AdministratorAdded { who: 1, role: AdminRole::Creator }.into());
+        assert_noop!(
+            EternalArtsModule::add_administrator(RuntimeOrigin::root(), 1, AdminRole::Minter),
+            Error::<Test>::AdministratorAlreadyExists
+        );
+        assert_ok!(EternalArtsModule::grant_role(RuntimeOrigin::root(), 1, AdminRole::Minter));
+        assert_ok!(EternalArtsModule::add_administrator(RuntimeOrigin::root(), 2, AdminRole::Minter));
+        assert_ok!(EternalArtsModule::add_administrator(RuntimeOrigin::root(), 3, AdminRole::Locker));
+        assert_eq!(Administrators::<Test>::count(), 3);
+
+        // At most `MaxAdministrators` accounts hold a role.
+        assert_noop!(
+            EternalArtsModule::add_administrator(RuntimeOrigin::root(), 4, AdminRole::Minter),
+            Error::<Test>::TooManyAdministrators
+        );
+        assert_noop!(
+            EternalArtsModule::grant_role(RuntimeOrigin::root(), 4, AdminRole::Minter),
+            Error::<Test>::TooManyAdministrators
+        );
+
+        // Removing one administrator leaves the others alone.
+        assert_ok!(EternalArtsModule::remove_administrator(RuntimeOrigin::root(), 1));
+        System::assert_last_event(Event::AdministratorRemoved {
+            who: 1,
+            roles: AdminRoles(AdminRole::Creator.flag() | AdminRole::Minter.flag()),
+        }.into());
+        assert!(!Administrators::<Test>::contains_key(1));
+        assert!(Administrators::<Test>::get(2).contains(&AdminRole::Minter));
+        assert_noop!(
+            EternalArtsModule::remove_administrator(RuntimeOrigin::root(), 1),
+            Error::<Test>::AdministratorNotFound
+        );
+        assert_ok!(EternalArtsModule::add_administrator(RuntimeOrigin::root(), 4, AdminRole::Minter));
+        assert_eq!(Administrators::<Test>::count(), 3);
+    });
+}
+
+#[test]
+fn it_works_for_administrator_expiry() {
+    new_test_ext().execute_with(|| {
+        use frame_support::{traits::Hooks, weights::Weight};
+
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
+
+        let art_name = NameOf::<Test>::create_on_vec(b"testName".to_vec());
+        let art_url = UriOf::<Test>::create_on_vec(b"https://abc.json".to_vec());
+
+        assert_ok!(EternalArtsModule::add_administrator(RuntimeOrigin::root(), 1, AdminRole::Creator));
+        assert_ok!(EternalArtsModule::add_administrator(RuntimeOrigin::root(), 2, AdminRole::Minter));
+        assert_noop!(
+            EternalArtsModule::set_administrator_expiry(RuntimeOrigin::root(), 3, Some(5)),
+            Error::<Test>::AdministratorNotFound
+        );
+        assert_noop!(
+            EternalArtsModule::set_administrator_expiry(RuntimeOrigin::root(), 1, Some(1)),
+            Error::<Test>::ExpiryInPast
+        );
+
+        assert_ok!(EternalArtsModule::set_administrator_expiry(RuntimeOrigin::root(), 1, Some(5)));
+        System::assert_last_event(Event::AdministratorExpirySet { who: 1, expires_at: Some(5) }.into());
+        assert_ok!(EternalArtsModule::set_administrator_expiry(RuntimeOrigin::root(), 2, Some(8)));
+        assert_eq!(EternalArtsModule::administrator_expiries(10), vec![(1, 5), (2, 8)]);
+        assert_eq!(EternalArtsModule::administrator_expiries(6), vec![(1, 5)]);
+
+        // Moving an expiry drops the old one.
+        assert_ok!(EternalArtsModule::set_administrator_expiry(RuntimeOrigin::root(), 2, Some(9)));
+        assert!(!AdministratorsByExpiry::<Test>::contains_key(8, 2));
+        assert_eq!(EternalArtsModule::administrator_expiries(10), vec![(1, 5), (2, 9)]);
+
+        assert_ok!(EternalArtsModule::create_art_collection(RuntimeOrigin::signed(1), art_name.clone(), art_url.clone()));
+
+        // Expired roles are ignored before they are pruned.
+        System::set_block_number(5);
+        assert_noop!(
+            EternalArtsModule::create_art_collection(RuntimeOrigin::signed(1), art_name.clone(), art_url.clone()),
+            Error::<Test>::NotAdministrator
+        );
+        assert!(Administrators::<Test>::contains_key(1));
+
+        // Each pruned administrator is charged its benchmarked weight.
+        let prune = <() as crate::WeightInfo>::prune_expired_administrator();
+        let cursor = <Test as frame_system::Config>::DbWeight::get().reads_writes(2, 1);
+        EternalArtsModule::on_idle(5, cursor.saturating_add(prune).saturating_sub(Weight::from_parts(1, 0)));
+        assert!(Administrators::<Test>::contains_key(1));
+        assert_eq!(NextAdministratorExpiry::<Test>::get(), Some(5));
+
+        EternalArtsModule::on_idle(5, Weight::MAX);
+        System::assert_last_event(Event::AdministratorExpired { who: 1, roles: AdminRoles(AdminRole::Creator.flag()) }.into());
+        assert!(!Administrators::<Test>::contains_key(1));
+        assert!(!AdministratorExpiries::<Test>::contains_key(1));
+        assert_eq!(NextAdministratorExpiry::<Test>::get(), Some(6));
+        assert_eq!(EternalArtsModule::administrator_expiries(10), vec![(2, 9)]);
+
+        // Walking blocks without expiries costs a read each.
+        let db_weight = <Test as frame_system::Config>::DbWeight::get();
+        assert_eq!(EternalArtsModule::on_idle(8, Weight::MAX), db_weight.reads_writes(2, 1).saturating_add(db_weight.reads(3)));
+        assert_eq!(NextAdministratorExpiry::<Test>::get(), Some(9));
+        assert!(Administrators::<Test>::contains_key(2));
+
+        // A permanent administrator is never pruned.
+        assert_ok!(EternalArtsModule::set_administrator_expiry(RuntimeOrigin::root(), 2, None));
+        EternalArtsModule::on_idle(9, Weight::MAX);
+        assert!(Administrators::<Test>::get(2).contains(&AdminRole::Minter));
+        assert_eq!(NextAdministratorExpiry::<Test>::get(), None);
+    });
+}
+
+#[test]
+fn it_works_for_granting_an_expired_administrator() {
+    new_test_ext().execute_with(|| {
+        use frame_support::{traits::Hooks, weights::Weight};
+
+        System::set_block_number(1);
+        assert_ok!(EternalArtsModule::add_administrator(RuntimeOrigin::root(), 1, AdminRole::Creator));
+        assert_ok!(EternalArtsModule::set_administrator_expiry(RuntimeOrigin::root(), 1, Some(5)));
+        assert_ok!(EternalArtsModule::add_administrator(RuntimeOrigin::root(), 2, AdminRole::Creator));
+        assert_ok!(EternalArtsModule::set_administrator_expiry(RuntimeOrigin::root(), 2, Some(5)));
+        System::set_block_number(5);
+
+        // Granting a role to an expired entry that was not pruned yet starts a new entry.
+        assert_ok!(EternalArtsModule::grant_role(RuntimeOrigin::root(), 1, AdminRole::Minter));
+        System::assert_last_event(Event::RoleGranted { who: 1, role: AdminRole::Minter }.into());
+        assert_eq!(Administrators::<Test>::get(1), AdminRoles(AdminRole::Minter.flag()));
+        assert!(!AdministratorExpiries::<Test>::contains_key(1));
+        assert!(!AdministratorsByExpiry::<Test>::contains_key(5, 1));
+        assert!(EternalArtsModule::is_administrator_list(&1, &AdminRole::Minter));
+        assert!(!EternalArtsModule::is_administrator_list(&1, &AdminRole::Creator));
+
+        // So does adding it again.
+        assert_ok!(EternalArtsModule::add_administrator(RuntimeOrigin::root(), 2, AdminRole::Minter));
+        assert_eq!(Administrators::<Test>::get(2), AdminRoles(AdminRole::Minter.flag()));
+        assert!(!AdministratorExpiries::<Test>::contains_key(2));
+        assert!(EternalArtsModule::is_administrator_list(&2, &AdminRole::Minter));
+
+        // Pruning leaves the new entries alone.
+        EternalArtsModule::on_idle(5, Weight::MAX);
+        assert_eq!(Administrators::<Test>::count(), 2);
     });
 }
 
@@ -24,13 +253,13 @@ fn correct_error_for_create_art_collection() {
         // Ensure the expected error is thrown when no value is present.
         System::set_block_number(1);
 
-        handler_set_admin_list(vec![(1, ADMIN_TYPE_IS_MINTER)]);
+        handler_set_admin_list(vec![(1, AdminRole::Minter)]);
 
-        let art_name = BoundedVec::<u8, TypeCollectionDataLength>::create_on_vec(b"testName".to_vec());
-        let art_url = BoundedVec::<u8, TypeCollectionDataLength>::create_on_vec(b"https://abc.json".to_vec());
+        let art_name = NameOf::<Test>::create_on_vec(b"testName".to_vec());
+        let art_url = UriOf::<Test>::create_on_vec(b"https://abc.json".to_vec());
 
         assert_noop!(
-			EternalArtsModule::create_art_collection(RuntimeOrigin::signed(1), 0, art_name.clone(), art_url.clone()),
+			EternalArtsModule::create_art_collection(RuntimeOrigin::signed(1), art_name.clone(), art_url.clone()),
 			Error::<Test>::NotAdministrator
 		);
     });
@@ -42,26 +271,61 @@ fn it_works_for_create_art_collection() {
         // Go past genesis block so events get deposited
         System::set_block_number(1);
 
-        handler_set_admin_list(vec![(1, ADMIN_TYPE_IS_CREATOR)]);
+        handler_set_admin_list(vec![(1, AdminRole::Creator)]);
 
-        let s_id: u64 = 1;
+        let s_id: u64 = 0;
 
-        let art_name = BoundedVec::<u8, TypeCollectionDataLength>::create_on_vec(b"testName".to_vec());
-        let art_url = BoundedVec::<u8, TypeCollectionDataLength>::create_on_vec(b"https://abc.json".to_vec());
-        assert_ok!(handler_create_art_collection(s_id.clone(), art_name.clone(), art_url.clone()));
+        let art_name = NameOf::<Test>::create_on_vec(b"testName".to_vec());
+        let art_url = UriOf::<Test>::create_on_vec(b"https://abc.json".to_vec());
+        assert_ok!(handler_create_art_collection(s_id, art_name.clone(), art_url.clone()));
+        assert_eq!(NextCollectionId::<Test>::get(), 1);
 
         assert_noop!(
-			EternalArtsModule::create_art_collection(RuntimeOrigin::signed(1), s_id.clone(), art_name.clone(), art_url.clone()),
+			EternalArtsModule::force_create_art_collection(RuntimeOrigin::root(), s_id, 1, art_name.clone(), art_url.clone()),
 			Error::<Test>::ArtCollectionIsExists
 		);
 
         // Check data, from ArtCollection
-        assert_eq!(ArtCollection::<Test>::get(s_id.clone()), Some(StructArtCollectionData {
+        assert_eq!(ArtCollection::<Test>::get(s_id), Some(StructArtCollectionData {
             name: art_name.clone(),
             uri: art_url.clone(),
+            multihash: None,
+            content_hash: None,
         }));
 
-        System::assert_last_event(Event::ArtCollectionCreated { s_id: s_id, name: art_name, uri: art_url }.into());
+        assert_eq!(CollectionOwner::<Test>::get(s_id), Some(1));
+        System::assert_last_event(Event::ArtCollectionCreated { s_id, owner: 1, name: art_name, uri: art_url }.into());
+    });
+}
+
+#[test]
+fn it_works_for_force_create_art_collection() {
+    new_test_ext().execute_with(|| {
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
+
+        handler_set_admin_list(vec![(1, AdminRole::Creator)]);
+
+        let art_name = NameOf::<Test>::create_on_vec(b"testName".to_vec());
+        let art_url = UriOf::<Test>::create_on_vec(b"https://abc.json".to_vec());
+
+        assert_noop!(
+            EternalArtsModule::force_create_art_collection(RuntimeOrigin::signed(1), 5, 2, art_name.clone(), art_url.clone()),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        // Legacy ids are imported as they are and the allocator skips past them.
+        assert_ok!(EternalArtsModule::force_create_art_collection(RuntimeOrigin::root(), 5, 2, art_name.clone(), art_url.clone()));
+        System::assert_last_event(Event::ArtCollectionCreated { s_id: 5, owner: 2, name: art_name.clone(), uri: art_url.clone() }.into());
+        assert_eq!(CollectionOwner::<Test>::get(5), Some(2));
+        assert_eq!(NextCollectionId::<Test>::get(), 6);
+
+        assert_ok!(EternalArtsModule::create_art_collection(RuntimeOrigin::signed(1), art_name.clone(), art_url.clone()));
+        System::assert_last_event(Event::ArtCollectionCreated { s_id: 6, owner: 1, name: art_name.clone(), uri: art_url.clone() }.into());
+
+        // Lower free ids can still be imported without moving the allocator back.
+        assert_ok!(EternalArtsModule::force_create_art_collection(RuntimeOrigin::root(), 2, 2, art_name.clone(), art_url.clone()));
+        assert_eq!(NextCollectionId::<Test>::get(), 7);
     });
 }
 
@@ -72,67 +336,164 @@ fn it_works_for_update_art_collection() {
         // Go past genesis block so events get deposited
         System::set_block_number(2);
 
-        handler_set_admin_list(vec![(1, ADMIN_TYPE_IS_CREATOR)]);
+        handler_set_admin_list(vec![(1, AdminRole::Creator)]);
 
-        let s_id: u64 = 1;
+        let s_id: u64 = 0;
 
-        let art_name = BoundedVec::<u8, TypeCollectionDataLength>::create_on_vec(b"testName".to_vec());
-        let art_url = BoundedVec::<u8, TypeCollectionDataLength>::create_on_vec(b"https://abc.json".to_vec());
+        let art_name = NameOf::<Test>::create_on_vec(b"testName".to_vec());
+        let art_url = UriOf::<Test>::create_on_vec(b"https://abc.json".to_vec());
 
-        assert_ok!(handler_create_art_collection(s_id.clone(), art_name.clone(), art_url.clone()));
+        assert_ok!(handler_create_art_collection(s_id, art_name.clone(), art_url.clone()));
         assert_eq!(ArtCollection::<Test>::get(s_id), Some(StructArtCollectionData {
             name: art_name.clone(),
             uri: art_url.clone(),
+            multihash: None,
+            content_hash: None,
         }));
 
-        let art_name = BoundedVec::<u8, TypeCollectionDataLength>::create_on_vec(b"testName-update".to_vec());
-        let art_url = BoundedVec::<u8, TypeCollectionDataLength>::create_on_vec(b"https://abc-update.json".to_vec());
+        let art_name = NameOf::<Test>::create_on_vec(b"testName-update".to_vec());
+        let art_url = UriOf::<Test>::create_on_vec(b"https://abc-update.json".to_vec());
 
         // Dispatch a signed extrinsic.
-        assert_ok!(EternalArtsModule::update_art_collection(RuntimeOrigin::signed(1), 1, art_name.clone(), art_url.clone()));
-        assert_eq!(ArtCollection::<Test>::get(s_id.clone()), Some(StructArtCollectionData {
+        assert_ok!(EternalArtsModule::update_art_collection(RuntimeOrigin::signed(1), 0, art_name.clone(), art_url.clone()));
+        assert_eq!(ArtCollection::<Test>::get(s_id), Some(StructArtCollectionData {
             name: art_name.clone(),
             uri: art_url.clone(),
+            multihash: None,
+            content_hash: None,
         }));
 
         // Assert that the correct event was deposited
-        System::assert_last_event(Event::ArtCollectionUpdated { s_id: 1, name: art_name, uri: art_url }.into());
+        System::assert_last_event(Event::ArtCollectionUpdated { s_id: 0, name: art_name, uri: art_url }.into());
     });
 }
 
 #[test]
-fn correct_error_for_mint_art_owner() {
+fn it_works_for_collection_scoped_administrators() {
     new_test_ext().execute_with(|| {
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
+
+        handler_set_admin_list(vec![(1, AdminRole::Creator), (2, AdminRole::Creator)]);
 
-        let (b_ids, s_ids, count_list) = handler_mint_param();
-        let error_s_ids:TypeSidList = vec![0, 0, 1, 0];
+        let art_name = NameOf::<Test>::create_on_vec(b"testName".to_vec());
+        let art_url = UriOf::<Test>::create_on_vec(b"https://abc.json".to_vec());
+        assert_ok!(EternalArtsModule::create_art_collection(RuntimeOrigin::signed(1), art_name.clone(), art_url.clone()));
+        assert_ok!(EternalArtsModule::create_art_collection(RuntimeOrigin::signed(2), art_name.clone(), art_url.clone()));
 
+        // Another creator can not touch the collection.
+        assert_noop!(
+            EternalArtsModule::update_art_collection(RuntimeOrigin::signed(2), 0, art_name.clone(), art_url.clone()),
+            Error::<Test>::NotAdministrator
+        );
+        assert_noop!(
+            EternalArtsModule::set_collection_status(RuntimeOrigin::signed(2), 0, true),
+            Error::<Test>::NotAdministrator
+        );
         assert_noop!(
-            EternalArtsModule::issue_art_ownership(RuntimeOrigin::signed(1), b_ids.clone(), s_ids.clone(), count_list.clone()),
+            EternalArtsModule::set_collection_limit(RuntimeOrigin::signed(2), 0, Some(1)),
             Error::<Test>::NotAdministrator
         );
 
-        handler_set_admin_list(vec![(1, ADMIN_TYPE_IS_MINTER), (1, ADMIN_TYPE_IS_CREATOR)]);
+        // Ids without a collection have no owner and take no status or roles ahead of time.
+        handler_set_admin_list(vec![(1, AdminRole::Creator), (2, AdminRole::Creator), (5, AdminRole::Locker)]);
+        assert_noop!(
+            EternalArtsModule::set_collection_status(RuntimeOrigin::signed(5), 2, true),
+            Error::<Test>::ArtCollectionNotFound
+        );
+        assert_noop!(
+            EternalArtsModule::revoke_collection_role(RuntimeOrigin::signed(1), 2, 3, AdminRole::Minter),
+            Error::<Test>::ArtCollectionNotFound
+        );
+        CollectionOwner::<Test>::remove(1);
+        assert!(EternalArtsModule::is_collection_owner(&1, &1));
+        assert!(!EternalArtsModule::is_collection_owner(&1, &2));
+        CollectionOwner::<Test>::insert(1, 2);
+
+        // The owner appoints a minter for its own collection only.
+        assert_noop!(
+            EternalArtsModule::grant_collection_role(RuntimeOrigin::signed(2), 0, 3, AdminRole::Minter),
+            Error::<Test>::NotAdministrator
+        );
+        assert_noop!(
+            EternalArtsModule::grant_collection_role(RuntimeOrigin::signed(1), 0, 3, AdminRole::Locker),
+            Error::<Test>::RoleNotAssignable
+        );
+        assert_ok!(EternalArtsModule::grant_collection_role(RuntimeOrigin::signed(1), 0, 3, AdminRole::Minter));
+        System::assert_last_event(Event::CollectionRoleGranted { s_id: 0, who: 3, role: AdminRole::Minter }.into());
 
+        let b_id = BidOf::<Test>::create_on_vec(b"releation-id-A".to_vec());
+        assert_ok!(EternalArtsModule::issue_art_ownership(RuntimeOrigin::signed(3), handler_mint_items(vec![(b_id.clone(), 0, 1)]), None));
         assert_noop!(
-            EternalArtsModule::issue_art_ownership(RuntimeOrigin::signed(1), b_ids.clone(), error_s_ids.clone(), count_list.clone()),
-            Error::<Test>::LengthNotMatch
+            EternalArtsModule::issue_art_ownership(RuntimeOrigin::signed(3), handler_mint_items(vec![(b_id.clone(), 0, 1), (b_id.clone(), 1, 1)]), None),
+            Error::<Test>::MintItemFailed { index: 1, error: MintItemError::NotAdministrator }
+        );
+        assert_noop!(
+            EternalArtsModule::update_art_collection(RuntimeOrigin::signed(3), 0, art_name.clone(), art_url.clone()),
+            Error::<Test>::NotAdministrator
         );
 
+        // An editor only edits.
+        assert_ok!(EternalArtsModule::grant_collection_role(RuntimeOrigin::signed(1), 0, 4, AdminRole::MetadataEditor));
+        assert_ok!(EternalArtsModule::update_art_collection(RuntimeOrigin::signed(4), 0, art_name.clone(), art_url.clone()));
         assert_noop!(
-            EternalArtsModule::issue_art_ownership(RuntimeOrigin::signed(1), b_ids.clone(), s_ids.clone(), count_list.clone()),
-            Error::<Test>::ArtCollectionNotFound
+            EternalArtsModule::set_collection_status(RuntimeOrigin::signed(4), 0, true),
+            Error::<Test>::NotAdministrator
+        );
+
+        assert_ok!(EternalArtsModule::revoke_collection_role(RuntimeOrigin::signed(1), 0, 3, AdminRole::Minter));
+        System::assert_last_event(Event::CollectionRoleRevoked { s_id: 0, who: 3, role: AdminRole::Minter }.into());
+        assert!(!CollectionAdministrators::<Test>::contains_key(0, 3));
+        assert_noop!(
+            EternalArtsModule::issue_art_ownership(RuntimeOrigin::signed(3), handler_mint_items(vec![(b_id.clone(), 0, 1)]), None),
+            Error::<Test>::MintItemFailed { index: 0, error: MintItemError::NotAdministrator }
+        );
+    });
+}
+
+#[test]
+fn correct_error_for_mint_art_owner() {
+    new_test_ext().execute_with(|| {
+
+        let (_, items) = handler_mint_param();
+
+        assert_noop!(
+            EternalArtsModule::issue_art_ownership(RuntimeOrigin::signed(1), items.clone(), None),
+            Error::<Test>::MintItemFailed { index: 0, error: MintItemError::NotAdministrator }
+        );
+
+        handler_set_admin_list(vec![(1, AdminRole::Minter), (1, AdminRole::Creator)]);
+
+        assert_noop!(
+            EternalArtsModule::issue_art_ownership(RuntimeOrigin::signed(1), handler_mint_items(vec![]), None),
+            Error::<Test>::EmptyBatch
+        );
+
+        assert_noop!(
+            EternalArtsModule::issue_art_ownership(RuntimeOrigin::signed(1), items.clone(), None),
+            Error::<Test>::MintItemFailed { index: 0, error: MintItemError::ArtCollectionNotFound }
         );
 
-        let art_name = BoundedVec::<u8, TypeCollectionDataLength>::create_on_vec(b"testName1".to_vec());
-        let art_url = BoundedVec::<u8, TypeCollectionDataLength>::create_on_vec(b"https://abc1.json".to_vec());
+        let art_name = NameOf::<Test>::create_on_vec(b"testName1".to_vec());
+        let art_url = UriOf::<Test>::create_on_vec(b"https://abc1.json".to_vec());
         assert_ok!(handler_create_art_collection(0, art_name.clone(), art_url.clone()));
 
+        // The third item points at a missing collection.
         assert_noop!(
-            EternalArtsModule::issue_art_ownership(RuntimeOrigin::signed(1), b_ids.clone(), s_ids.clone(), count_list.clone()),
-            Error::<Test>::ArtCollectionNotFound
+            EternalArtsModule::issue_art_ownership(RuntimeOrigin::signed(1), items.clone(), None),
+            Error::<Test>::MintItemFailed { index: 2, error: MintItemError::ArtCollectionNotFound }
         );
 
+        // Batches longer than `MaxBatch` can not be built.
+        let b_id = BidOf::<Test>::create_on_vec(b"releation-id-A".to_vec());
+        let oversized: Vec<MintItemOf<Test>> = (0..=DebugMaxBachSize::get()).map(|_| MintItem { b_id: b_id.clone(), s_id: 0, count: 1 }).collect();
+        assert!(MintItemsOf::<Test>::try_from(oversized.clone()).is_err());
+        assert_ok!(EternalArtsModule::issue_art_ownership(
+            RuntimeOrigin::signed(1),
+            MintItemsOf::<Test>::try_from(oversized[1..].to_vec()).unwrap(),
+            None
+        ));
+        assert_eq!(NftCount::<Test>::get(0), DebugMaxBachSize::get() as u64);
     });
 }
 
@@ -143,71 +504,1105 @@ fn it_works_for_issue_art_ownership() {
         // Go past genesis block so events get deposited
         System::set_block_number(1);
 
-        handler_set_admin_list(vec![(1u64, ADMIN_TYPE_IS_CREATOR), (1u64, ADMIN_TYPE_IS_MINTER)]);
+        handler_set_admin_list(vec![(1u64, AdminRole::Creator), (1u64, AdminRole::Minter)]);
 
-        let art_name = BoundedVec::<u8, TypeCollectionDataLength>::create_on_vec(b"testName1".to_vec());
-        let art_url = BoundedVec::<u8, TypeCollectionDataLength>::create_on_vec(b"https://abc1.json".to_vec());
+        let art_name = NameOf::<Test>::create_on_vec(b"testName1".to_vec());
+        let art_url = UriOf::<Test>::create_on_vec(b"https://abc1.json".to_vec());
         assert_ok!(handler_create_art_collection(0, art_name.clone(), art_url.clone()));
 
-        let art_name = BoundedVec::<u8, TypeCollectionDataLength>::create_on_vec(b"testName2".to_vec());
-        let art_url = BoundedVec::<u8, TypeCollectionDataLength>::create_on_vec(b"https://abc2.json".to_vec());
+        let art_name = NameOf::<Test>::create_on_vec(b"testName2".to_vec());
+        let art_url = UriOf::<Test>::create_on_vec(b"https://abc2.json".to_vec());
         assert_ok!(handler_create_art_collection(1, art_name.clone(), art_url.clone()));
 
-        let (b_ids, s_ids, count_list) = handler_mint_param();
+        let (b_ids, items) = handler_mint_param();
 
         // Lock collection
         EternalArtsModule::set_collection_status(RuntimeOrigin::signed(1), 0, true);
 
         assert_noop!(
-            EternalArtsModule::issue_art_ownership(RuntimeOrigin::signed(1), b_ids.clone(), s_ids.clone(), count_list.clone()),
-            Error::<Test>::ArtCollectionIsLocked
+            EternalArtsModule::issue_art_ownership(RuntimeOrigin::signed(1), items.clone(), None),
+            Error::<Test>::MintItemFailed { index: 0, error: MintItemError::ArtCollectionIsLocked }
         );
 
         // Unlock collection
         EternalArtsModule::set_collection_status(RuntimeOrigin::signed(1), 0, false);
 
-        assert_ok!(EternalArtsModule::issue_art_ownership(RuntimeOrigin::signed(1), b_ids.clone(), s_ids.clone(), count_list.clone()));
+        assert_ok!(EternalArtsModule::issue_art_ownership(RuntimeOrigin::signed(1), items.clone(), None));
 
         assert_eq!(NftCount::<Test>::get(0), 3);
         assert_eq!(NftCount::<Test>::get(1), 5);
         assert_eq!(NftCount::<Test>::get(2), 0);
 
-        assert_eq!(NftBindInfos::<Test>::get((TypeBid::create_on_vec(b"releation-id-A".to_vec()), 0)),1);
-        assert_eq!(NftBindInfos::<Test>::get((TypeBid::create_on_vec(b"releation-id-B".to_vec()), 0)),2);
-        assert_eq!(NftBindInfos::<Test>::get((TypeBid::create_on_vec(b"releation-id-C".to_vec()), 0)),0);
+        assert_eq!(NftBindInfos::<Test>::get(BidOf::<Test>::create_on_vec(b"releation-id-A".to_vec()), 0),1);
+        assert_eq!(NftBindInfos::<Test>::get(BidOf::<Test>::create_on_vec(b"releation-id-B".to_vec()), 0),2);
+        assert_eq!(NftBindInfos::<Test>::get(BidOf::<Test>::create_on_vec(b"releation-id-C".to_vec()), 0),0);
+
+        assert_eq!(NftBindInfos::<Test>::get(BidOf::<Test>::create_on_vec(b"releation-id-A".to_vec()), 1),0);
+        assert_eq!(NftBindInfos::<Test>::get(BidOf::<Test>::create_on_vec(b"releation-id-B".to_vec()), 1),0);
+        assert_eq!(NftBindInfos::<Test>::get(BidOf::<Test>::create_on_vec(b"releation-id-C".to_vec()), 1),5);
 
-        assert_eq!(NftBindInfos::<Test>::get((TypeBid::create_on_vec(b"releation-id-A".to_vec()), 1)),0);
-        assert_eq!(NftBindInfos::<Test>::get((TypeBid::create_on_vec(b"releation-id-B".to_vec()), 1)),0);
-        assert_eq!(NftBindInfos::<Test>::get((TypeBid::create_on_vec(b"releation-id-C".to_vec()), 1)),5);
+        // Both directions are indexed.
+        let mut holders = EternalArtsModule::holders_of(&0);
+        holders.sort();
+        assert_eq!(holders, vec![(b_ids[0].clone(), 1), (b_ids[1].clone(), 2)]);
+        assert_eq!(EternalArtsModule::collections_of(&b_ids[2]), vec![(1, 5)]);
 
-        System::assert_last_event(Event::MintArtOwner { bn: 1, b_ids: b_ids, s_ids: s_ids, count: count_list }.into());
+        System::assert_last_event(Event::MintArtOwner { bn: 1, items, request_id: None }.into());
     });
 }
 
+#[test]
+fn it_works_for_issue_art_ownership_best_effort() {
+    new_test_ext().execute_with(|| {
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
+
+        handler_set_admin_list(vec![(1u64, AdminRole::Creator), (1u64, AdminRole::Minter)]);
+
+        let art_name = NameOf::<Test>::create_on_vec(b"testName1".to_vec());
+        let art_url = UriOf::<Test>::create_on_vec(b"https://abc1.json".to_vec());
+        assert_ok!(handler_create_art_collection(0, art_name.clone(), art_url.clone()));
+        assert_ok!(handler_create_art_collection(1, art_name.clone(), art_url.clone()));
+        assert_ok!(EternalArtsModule::set_collection_status(RuntimeOrigin::signed(1), 1, true));
+
+        let (b_ids, _) = handler_mint_param();
+        let items = handler_mint_items(vec![
+            (b_ids[0].clone(), 0, 1),
+            (b_ids[1].clone(), 1, 1),
+            (b_ids[2].clone(), 7, 1),
+            (b_ids[0].clone(), 0, 2),
+        ]);
+
+        assert_noop!(
+            EternalArtsModule::issue_art_ownership_best_effort(RuntimeOrigin::signed(1), handler_mint_items(vec![]), None),
+            Error::<Test>::EmptyBatch
+        );
+
+        let post_info = EternalArtsModule::issue_art_ownership_best_effort(RuntimeOrigin::signed(1), items.clone(), None).unwrap();
+
+        // Only the valid items were applied.
+        assert_eq!(NftCount::<Test>::get(0), 3);
+        assert!(!NftCount::<Test>::contains_key(1));
+        assert_eq!(NftBindInfos::<Test>::get(b_ids[0].clone(), 0), 3);
+        assert!(!NftBindInfos::<Test>::contains_key(b_ids[1].clone(), 1));
+        assert!(!NftHolders::<Test>::contains_key(1, b_ids[1].clone()));
+        assert!(!NftCount::<Test>::contains_key(7));
+        assert!(!NftBindInfos::<Test>::contains_key(b_ids[2].clone(), 7));
+        assert!(!NftHolders::<Test>::contains_key(7, b_ids[2].clone()));
+
+        let results = BoundedVec::create_on_vec(vec![
+            Ok(()),
+            Err(MintItemError::ArtCollectionIsLocked),
+            Err(MintItemError::ArtCollectionNotFound),
+            Ok(()),
+        ]);
+        System::assert_last_event(Event::MintArtOwnerBestEffort { bn: 1, items: items.clone(), results, request_id: None }.into());
 
-fn handler_set_admin_list(administrators: Vec<(u64, u8)>) {
-    assert_ok!(EternalArtsModule::update_administrator_list(RuntimeOrigin::root(), administrators.clone()));
+        // The skipped items are refunded.
+        let declared = <() as crate::WeightInfo>::issue_art_ownership_best_effort(4);
+        let actual = post_info.actual_weight.unwrap();
+        assert_eq!(actual, <() as crate::WeightInfo>::issue_art_ownership_best_effort(2));
+        assert!(actual.all_lt(declared));
+
+        // An item failing after it raised the supply is rolled back on its own.
+        NftBindInfos::<Test>::insert(b_ids[2].clone(), 0, u64::MAX);
+        let items = handler_mint_items(vec![(b_ids[2].clone(), 0, 1), (b_ids[1].clone(), 0, 1)]);
+        assert_ok!(EternalArtsModule::issue_art_ownership_best_effort(RuntimeOrigin::signed(1), items, None));
+        assert_eq!(NftCount::<Test>::get(0), 4);
+        assert_eq!(NftBindInfos::<Test>::get(b_ids[2].clone(), 0), u64::MAX);
+        assert!(!NftHolders::<Test>::contains_key(0, b_ids[2].clone()));
+        assert_eq!(NftBindInfos::<Test>::get(b_ids[1].clone(), 0), 1);
+        assert!(NftHolders::<Test>::contains_key(0, b_ids[1].clone()));
+    });
 }
 
-fn handler_create_art_collection(s_id: u64, art_name: BoundedVec::<u8, TypeCollectionDataLength>, art_url: BoundedVec::<u8, TypeCollectionDataLength>) -> Result<(), &'static str> {
-    assert_ok!(EternalArtsModule::create_art_collection(RuntimeOrigin::signed(1), s_id.clone(), art_name.clone(), art_url.clone()));
+#[test]
+fn it_works_for_request_ids() {
+    new_test_ext().execute_with(|| {
+        use frame_support::{traits::Hooks, weights::Weight};
 
-    // Check data, from ArtCollection
-    assert_eq!(ArtCollection::<Test>::get(s_id), Some(StructArtCollectionData {
-        name: art_name.clone(),
-        uri: art_url.clone(),
-    }));
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
 
-    return Ok(());
+        handler_set_admin_list(vec![(1u64, AdminRole::Creator), (1u64, AdminRole::Minter), (2u64, AdminRole::Minter)]);
+
+        let art_name = NameOf::<Test>::create_on_vec(b"testName1".to_vec());
+        let art_url = UriOf::<Test>::create_on_vec(b"https://abc1.json".to_vec());
+        assert_ok!(handler_create_art_collection(0, art_name.clone(), art_url.clone()));
+
+        let b_id = BidOf::<Test>::create_on_vec(b"releation-id-A".to_vec());
+        let items = handler_mint_items(vec![(b_id.clone(), 0, 1)]);
+        let request_id = TypeRequestId::create_on_vec(b"order-1".to_vec());
+
+        assert_ok!(EternalArtsModule::issue_art_ownership(RuntimeOrigin::signed(1), items.clone(), Some(request_id.clone())));
+        System::assert_last_event(Event::MintArtOwner { bn: 1, items: items.clone(), request_id: Some(request_id.clone()) }.into());
+        assert!(EternalArtsModule::is_request_processed(&1, &request_id));
+
+        // Retries of the same order are rejected in both modes.
+        assert_noop!(
+            EternalArtsModule::issue_art_ownership(RuntimeOrigin::signed(1), items.clone(), Some(request_id.clone())),
+            Error::<Test>::RequestAlreadyProcessed
+        );
+        assert_noop!(
+            EternalArtsModule::issue_art_ownership_best_effort(RuntimeOrigin::signed(1), items.clone(), Some(request_id.clone())),
+            Error::<Test>::RequestAlreadyProcessed
+        );
+        assert_eq!(NftCount::<Test>::get(0), 1);
+
+        // A rejected batch does not use up its request id.
+        let other_request_id = TypeRequestId::create_on_vec(b"order-2".to_vec());
+        assert_noop!(
+            EternalArtsModule::issue_art_ownership(RuntimeOrigin::signed(1), handler_mint_items(vec![(b_id.clone(), 7, 1)]), Some(other_request_id.clone())),
+            Error::<Test>::MintItemFailed { index: 0, error: MintItemError::ArtCollectionNotFound }
+        );
+        assert!(!EternalArtsModule::is_request_processed(&1, &other_request_id));
+        // Neither does a best-effort batch that issued nothing.
+        assert_ok!(EternalArtsModule::issue_art_ownership_best_effort(
+            RuntimeOrigin::signed(1),
+            handler_mint_items(vec![(b_id.clone(), 7, 1)]),
+            Some(other_request_id.clone())
+        ));
+        assert!(!EternalArtsModule::is_request_processed(&1, &other_request_id));
+
+        // Processed ids expire after `RequestIdExpiry` blocks.
+        assert_eq!(NextRequestExpiry::<Test>::get(), Some(11));
+        EternalArtsModule::on_idle(10, Weight::MAX);
+        assert!(EternalArtsModule::is_request_processed(&1, &request_id));
+        EternalArtsModule::on_idle(11, Weight::MAX);
+        assert!(!EternalArtsModule::is_request_processed(&1, &request_id));
+        assert!(!RequestExpiries::<Test>::contains_key(11, (1, request_id.clone())));
+        assert_eq!(NextRequestExpiry::<Test>::get(), None);
+
+        System::set_block_number(12);
+        assert_ok!(EternalArtsModule::issue_art_ownership(RuntimeOrigin::signed(1), items.clone(), Some(request_id.clone())));
+        assert_eq!(NftCount::<Test>::get(0), 2);
+
+        // Request ids are scoped to the caller.
+        assert!(!EternalArtsModule::is_request_processed(&2, &request_id));
+        assert_ok!(EternalArtsModule::issue_art_ownership(RuntimeOrigin::signed(2), items.clone(), Some(request_id.clone())));
+        assert!(EternalArtsModule::is_request_processed(&2, &request_id));
+        assert_eq!(NftCount::<Test>::get(0), 3);
+    });
+}
+
+#[test]
+fn it_works_for_runtime_api_queries() {
+    new_test_ext().execute_with(|| {
+        handler_set_admin_list(vec![(1u64, AdminRole::Creator), (1u64, AdminRole::Minter)]);
+
+        assert_eq!(EternalArtsModule::collection_info(&0), None);
+
+        let art_name = NameOf::<Test>::create_on_vec(b"testName1".to_vec());
+        let art_url = UriOf::<Test>::create_on_vec(b"https://abc1.json".to_vec());
+        assert_ok!(handler_create_art_collection(0, art_name.clone(), art_url.clone()));
+
+        let b_ids: Vec<BidOf<Test>> = (0u8..4).map(|i| BidOf::<Test>::create_on_vec(vec![b'r', i])).collect();
+        assert_ok!(EternalArtsModule::issue_art_ownership(
+            RuntimeOrigin::signed(1),
+            handler_mint_items(b_ids.iter().cloned().zip(1..=4).map(|(b_id, count)| (b_id, 0, count)).collect()),
+            None
+        ));
+
+        assert_eq!(EternalArtsModule::collection_info(&0), Some(CollectionInfo {
+            data: StructArtCollectionData { name: art_name.into_inner(), uri: art_url.into_inner(), multihash: None, content_hash: None },
+            status: StructArtStatus::default(),
+            owner: Some(1),
+            total_supply: 10,
+        }));
+
+        // Walk the holders page by page.
+        let first_page = EternalArtsModule::holders_of_paged(&0, None, 3);
+        assert_eq!(first_page.len(), 3);
+        let second_page = EternalArtsModule::holders_of_paged(&0, first_page.last().map(|(b_id, _)| b_id.clone()), 3);
+        assert_eq!(second_page.len(), 1);
+
+        let mut holders = first_page;
+        holders.extend(second_page);
+        holders.sort();
+        assert_eq!(holders, b_ids.into_iter().zip(vec![1, 2, 3, 4]).collect::<Vec<_>>());
+    });
+}
+
+#[test]
+fn it_works_for_set_collection_limit() {
+    new_test_ext().execute_with(|| {
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
+
+        handler_set_admin_list(vec![(1u64, AdminRole::Creator), (1u64, AdminRole::Minter)]);
+
+        assert_noop!(
+            EternalArtsModule::set_collection_limit(RuntimeOrigin::signed(1), 0, Some(3)),
+            Error::<Test>::ArtCollectionNotFound
+        );
+
+        let art_name = NameOf::<Test>::create_on_vec(b"testName1".to_vec());
+        let art_url = UriOf::<Test>::create_on_vec(b"https://abc1.json".to_vec());
+        assert_ok!(handler_create_art_collection(0, art_name.clone(), art_url.clone()));
+
+        assert_ok!(EternalArtsModule::set_collection_limit(RuntimeOrigin::signed(1), 0, Some(3)));
+        assert_eq!(ArtStatus::<Test>::get(0).limit, Some(3));
+        System::assert_last_event(Event::UpdateArtStatus { s_id: 0, limit: Some(3), art_type: ArtType::OpenEdition, locked: false }.into());
+
+        let b_id = BidOf::<Test>::create_on_vec(b"releation-id-A".to_vec());
+        assert_ok!(EternalArtsModule::issue_art_ownership(RuntimeOrigin::signed(1), handler_mint_items(vec![(b_id.clone(), 0, 1), (b_id.clone(), 0, 1)]), None));
+
+        // The whole batch is rejected once the limit would be exceeded.
+        assert_noop!(
+            EternalArtsModule::issue_art_ownership(RuntimeOrigin::signed(1), handler_mint_items(vec![(b_id.clone(), 0, 1), (b_id.clone(), 0, 1)]), None),
+            Error::<Test>::MintItemFailed { index: 1, error: MintItemError::ArtCollectionLimitExceeded }
+        );
+        assert_noop!(
+            EternalArtsModule::set_collection_limit(RuntimeOrigin::signed(1), 0, Some(1)),
+            Error::<Test>::ArtCollectionLimitTooLow
+        );
+
+        assert_ok!(EternalArtsModule::issue_art_ownership(RuntimeOrigin::signed(1), handler_mint_items(vec![(b_id.clone(), 0, 1)]), None));
+        assert_eq!(NftCount::<Test>::get(0), 3);
+
+        // Explicitly unlimited.
+        assert_ok!(EternalArtsModule::set_collection_limit(RuntimeOrigin::signed(1), 0, None));
+        assert_ok!(EternalArtsModule::issue_art_ownership(RuntimeOrigin::signed(1), handler_mint_items(vec![(b_id.clone(), 0, 10)]), None));
+        assert_eq!(NftCount::<Test>::get(0), 13);
+        assert_eq!(NftBindInfos::<Test>::get(b_id, 0), 13);
+    });
+}
+
+#[test]
+fn it_works_for_set_collection_type() {
+    new_test_ext().execute_with(|| {
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
+
+        handler_set_admin_list(vec![(1u64, AdminRole::Creator), (1u64, AdminRole::Minter)]);
+
+        let art_name = NameOf::<Test>::create_on_vec(b"testName1".to_vec());
+        let art_url = UriOf::<Test>::create_on_vec(b"https://abc1.json".to_vec());
+        assert_ok!(handler_create_art_collection(0, art_name.clone(), art_url.clone()));
+        assert_ok!(handler_create_art_collection(1, art_name.clone(), art_url.clone()));
+
+        let b_id = BidOf::<Test>::create_on_vec(b"releation-id-A".to_vec());
+
+        // A unique collection issues a single unit in total.
+        assert_ok!(EternalArtsModule::set_collection_type(RuntimeOrigin::signed(1), 0, ArtType::Unique));
+        System::assert_last_event(Event::UpdateArtStatus { s_id: 0, limit: None, art_type: ArtType::Unique, locked: false }.into());
+        assert_noop!(
+            EternalArtsModule::issue_art_ownership(RuntimeOrigin::signed(1), handler_mint_items(vec![(b_id.clone(), 0, 2)]), None),
+            Error::<Test>::MintItemFailed { index: 0, error: MintItemError::ArtCollectionLimitExceeded }
+        );
+        assert_ok!(EternalArtsModule::issue_art_ownership(RuntimeOrigin::signed(1), handler_mint_items(vec![(b_id.clone(), 0, 1)]), None));
+        assert_noop!(
+            EternalArtsModule::set_collection_type(RuntimeOrigin::signed(1), 0, ArtType::OpenEdition),
+            Error::<Test>::ArtCollectionAlreadyIssued
+        );
+
+        // A limited edition needs its limit first.
+        assert_ok!(EternalArtsModule::set_collection_type(RuntimeOrigin::signed(1), 1, ArtType::LimitedEdition));
+        assert_noop!(
+            EternalArtsModule::issue_art_ownership(RuntimeOrigin::signed(1), handler_mint_items(vec![(b_id.clone(), 1, 1)]), None),
+            Error::<Test>::MintItemFailed { index: 0, error: MintItemError::ArtCollectionLimitNotSet }
+        );
+        assert_ok!(EternalArtsModule::set_collection_limit(RuntimeOrigin::signed(1), 1, Some(2)));
+        assert_ok!(EternalArtsModule::issue_art_ownership(RuntimeOrigin::signed(1), handler_mint_items(vec![(b_id.clone(), 1, 2)]), None));
+        assert_eq!(NftCount::<Test>::get(1), 2);
+    });
+}
+
+#[test]
+fn it_works_for_transfer_art_ownership() {
+    new_test_ext().execute_with(|| {
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
+
+        handler_set_admin_list(vec![(1u64, AdminRole::Creator), (1u64, AdminRole::Minter)]);
+
+        let art_name = NameOf::<Test>::create_on_vec(b"testName1".to_vec());
+        let art_url = UriOf::<Test>::create_on_vec(b"https://abc1.json".to_vec());
+        assert_ok!(handler_create_art_collection(0, art_name.clone(), art_url.clone()));
+
+        let (b_ids, _) = handler_mint_param();
+        let b_id_a = b_ids[0].clone();
+        let b_id_b = b_ids[1].clone();
+        let b_id_c = b_ids[2].clone();
+        assert_ok!(EternalArtsModule::issue_art_ownership(RuntimeOrigin::signed(1), handler_mint_items(vec![(b_id_a.clone(), 0, 1), (b_id_b.clone(), 0, 2)]), None));
+
+        assert_noop!(
+            EternalArtsModule::transfer_art_ownership(RuntimeOrigin::signed(2), b_id_b.clone(), b_id_c.clone(), 0, 1),
+            Error::<Test>::NotAdministrator
+        );
+        assert_noop!(
+            EternalArtsModule::transfer_art_ownership(RuntimeOrigin::signed(1), b_id_b.clone(), b_id_c.clone(), 0, 3),
+            Error::<Test>::InsufficientOwnership
+        );
+
+        assert_ok!(EternalArtsModule::transfer_art_ownership(RuntimeOrigin::signed(1), b_id_b.clone(), b_id_c.clone(), 0, 1));
+        assert_eq!(NftBindInfos::<Test>::get(b_id_b.clone(), 0), 1);
+        assert_eq!(NftBindInfos::<Test>::get(b_id_c.clone(), 0), 1);
+        assert_eq!(NftCount::<Test>::get(0), 3);
+        System::assert_last_event(Event::TransferArtOwner { s_id: 0, from_bid: b_id_b.clone(), to_bid: b_id_c.clone(), count: 1 }.into());
+
+        // Empty entries are removed.
+        assert_ok!(EternalArtsModule::transfer_art_ownership(RuntimeOrigin::signed(1), b_id_a.clone(), b_id_c.clone(), 0, 1));
+        assert!(!NftBindInfos::<Test>::contains_key(b_id_a.clone(), 0));
+        assert_eq!(NftBindInfos::<Test>::get(b_id_c.clone(), 0), 2);
+
+        // Locked collections can not move.
+        assert_ok!(EternalArtsModule::set_collection_status(RuntimeOrigin::signed(1), 0, true));
+        assert_noop!(
+            EternalArtsModule::transfer_art_ownership(RuntimeOrigin::signed(1), b_id_c.clone(), b_id_a.clone(), 0, 1),
+            Error::<Test>::ArtCollectionIsLocked
+        );
+
+        // Soulbound collections can not move either.
+        assert_ok!(handler_create_art_collection(1, art_name.clone(), art_url.clone()));
+        assert_ok!(EternalArtsModule::set_collection_type(RuntimeOrigin::signed(1), 1, ArtType::Soulbound));
+        assert_ok!(EternalArtsModule::issue_art_ownership(RuntimeOrigin::signed(1), handler_mint_items(vec![(b_id_a.clone(), 1, 1)]), None));
+        assert_noop!(
+            EternalArtsModule::transfer_art_ownership(RuntimeOrigin::signed(1), b_id_a.clone(), b_id_b.clone(), 1, 1),
+            Error::<Test>::ArtCollectionNotTransferable
+        );
+    });
+}
+
+#[test]
+fn it_works_for_revoke_art_ownership() {
+    new_test_ext().execute_with(|| {
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
+
+        handler_set_admin_list(vec![(1u64, AdminRole::Creator), (1u64, AdminRole::Minter)]);
+
+        let art_name = NameOf::<Test>::create_on_vec(b"testName1".to_vec());
+        let art_url = UriOf::<Test>::create_on_vec(b"https://abc1.json".to_vec());
+        assert_ok!(handler_create_art_collection(0, art_name.clone(), art_url.clone()));
+        assert_ok!(handler_create_art_collection(1, art_name.clone(), art_url.clone()));
+
+        let (b_ids, items) = handler_mint_param();
+        assert_ok!(EternalArtsModule::issue_art_ownership(RuntimeOrigin::signed(1), items.clone(), None));
+
+        assert_noop!(
+            EternalArtsModule::revoke_art_ownership(RuntimeOrigin::signed(2), items.clone(), RevokeReason::Mistake),
+            Error::<Test>::MintItemFailed { index: 0, error: MintItemError::NotAdministrator }
+        );
+        assert_noop!(
+            EternalArtsModule::revoke_art_ownership(RuntimeOrigin::signed(1), handler_mint_items(vec![]), RevokeReason::Mistake),
+            Error::<Test>::EmptyBatch
+        );
+        // Underflow of a single item rolls back the whole batch.
+        assert_noop!(
+            EternalArtsModule::revoke_art_ownership(
+                RuntimeOrigin::signed(1),
+                handler_mint_items(vec![(b_ids[0].clone(), 0, 1), (b_ids[1].clone(), 0, 3), (b_ids[2].clone(), 1, 5)]),
+                RevokeReason::Mistake
+            ),
+            Error::<Test>::MintItemFailed { index: 1, error: MintItemError::InsufficientOwnership }
+        );
+
+        let revoked = handler_mint_items(vec![(b_ids[0].clone(), 0, 1), (b_ids[1].clone(), 0, 1), (b_ids[2].clone(), 1, 5)]);
+        assert_ok!(EternalArtsModule::revoke_art_ownership(RuntimeOrigin::signed(1), revoked.clone(), RevokeReason::Fraud));
+        assert_eq!(NftCount::<Test>::get(0), 1);
+        assert!(!NftCount::<Test>::contains_key(1));
+        assert!(!NftBindInfos::<Test>::contains_key(b_ids[0].clone(), 0));
+        assert_eq!(NftBindInfos::<Test>::get(b_ids[1].clone(), 0), 1);
+        assert!(!NftBindInfos::<Test>::contains_key(b_ids[2].clone(), 1));
+        System::assert_last_event(Event::RevokeArtOwner { bn: 1, items: revoked, reason: RevokeReason::Fraud }.into());
+
+        // Revoking more units than the supply holds is an underflow, not an overflow.
+        NftBindInfos::<Test>::insert(b_ids[1].clone(), 0, 5);
+        assert_noop!(
+            EternalArtsModule::revoke_art_ownership(RuntimeOrigin::signed(1), handler_mint_items(vec![(b_ids[1].clone(), 0, 5)]), RevokeReason::Mistake),
+            Error::<Test>::MintItemFailed { index: 0, error: MintItemError::StorageUnderflow }
+        );
+    });
+}
+
+#[test]
+fn it_works_for_separate_instances() {
+    new_test_ext().execute_with(|| {
+        use frame_support::instances::Instance1;
+
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
+
+        let art_name = NameOf::<Test>::create_on_vec(b"testName1".to_vec());
+        let art_url = UriOf::<Test>::create_on_vec(b"https://abc1.json".to_vec());
+
+        // Each registry has its own administrators.
+        assert_ok!(EternalArtsMuseum::add_administrator(RuntimeOrigin::root(), 1, AdminRole::Creator));
+        assert!(!Administrators::<Test>::contains_key(1));
+        assert_noop!(
+            EternalArtsModule::create_art_collection(RuntimeOrigin::signed(1), art_name.clone(), art_url.clone()),
+            Error::<Test>::NotAdministrator
+        );
+
+        // And its own collection ids.
+        assert_ok!(EternalArtsMuseum::create_art_collection(RuntimeOrigin::signed(1), art_name.clone(), art_url.clone()));
+        System::assert_last_event(RuntimeEvent::EternalArtsMuseum(Event::<Test, Instance1>::ArtCollectionCreated {
+            s_id: 0,
+            owner: 1,
+            name: art_name.clone(),
+            uri: art_url.clone(),
+        }));
+        assert!(ArtCollection::<Test, Instance1>::contains_key(0));
+        assert!(!ArtCollection::<Test>::contains_key(0));
+
+        handler_set_admin_list(vec![(2, AdminRole::Creator)]);
+        assert_ok!(EternalArtsModule::create_art_collection(RuntimeOrigin::signed(2), art_name.clone(), art_url.clone()));
+        assert_eq!(CollectionOwner::<Test>::get(0), Some(2));
+        assert_eq!(CollectionOwner::<Test, Instance1>::get(0), Some(1));
+    });
+}
+
+#[test]
+fn it_works_for_nonfungibles() {
+    new_test_ext().execute_with(|| {
+        use frame_support::traits::tokens::nonfungibles_v2::{Create, Inspect};
+
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
+
+        let config = StructArtCollectionData {
+            name: NameOf::<Test>::create_on_vec(b"testName1".to_vec()),
+            uri: UriOf::<Test>::create_on_vec(b"https://abc1.json".to_vec()),
+            multihash: None,
+            content_hash: Some([7; 32]),
+        };
+        assert_eq!(<EternalArtsModule as Create<u64, _>>::create_collection(&1, &2, &config), Ok(0));
+        System::assert_last_event(Event::CollectionRoleGranted { s_id: 0, who: 2, role: AdminRole::MetadataEditor }.into());
+        assert_eq!(<EternalArtsModule as Inspect<u64>>::collection_owner(&0), Some(1));
+        assert!(CollectionAdministrators::<Test>::get(0, 2).contains(&AdminRole::Minter));
+        assert_eq!(<EternalArtsModule as Inspect<u64>>::collection_attribute(&0, b"uri"), Some(b"https://abc1.json".to_vec()));
+        assert_eq!(<EternalArtsModule as Inspect<u64>>::collection_attribute(&0, b"content_hash"), Some(vec![7; 32]));
+        assert_eq!(<EternalArtsModule as Inspect<u64>>::collection_attribute(&0, b"artist"), None);
+        assert_noop!(
+            <EternalArtsModule as Create<u64, _>>::create_collection(&1, &2, &config),
+            Error::<Test>::DuplicateContentHash
+        );
+
+        // Items are the relation ids holding units, kept by the collection owner.
+        let b_id = BidOf::<Test>::create_on_vec(b"releation-id-A".to_vec());
+        assert_eq!(<EternalArtsModule as Inspect<u64>>::owner(&0, &b_id), None);
+        assert!(!<EternalArtsModule as Inspect<u64>>::can_transfer(&0, &b_id));
+        let items = handler_mint_items(vec![(b_id.clone(), 0, 1)]);
+        assert_ok!(EternalArtsModule::issue_art_ownership(RuntimeOrigin::signed(1), items.clone(), None));
+        assert_eq!(<EternalArtsModule as Inspect<u64>>::owner(&0, &b_id), Some(1));
+        assert!(<EternalArtsModule as Inspect<u64>>::can_transfer(&0, &b_id));
+        assert!(!<EternalArtsModule as Inspect<u64>>::can_transfer(&1, &b_id));
+
+        assert_ok!(EternalArtsModule::revoke_art_ownership(RuntimeOrigin::signed(1), items.clone(), RevokeReason::Mistake));
+        assert_eq!(<EternalArtsModule as Inspect<u64>>::owner(&0, &b_id), None);
+        assert!(!<EternalArtsModule as Inspect<u64>>::can_transfer(&0, &b_id));
+
+        // Soulbound editions can not be transferred.
+        assert_ok!(EternalArtsModule::set_collection_type(RuntimeOrigin::signed(1), 0, ArtType::Soulbound));
+        assert_ok!(EternalArtsModule::issue_art_ownership(RuntimeOrigin::signed(1), items, None));
+        assert!(!<EternalArtsModule as Inspect<u64>>::can_transfer(&0, &b_id));
+    });
+}
+
+#[test]
+fn it_works_for_fungibles() {
+    new_test_ext().execute_with(|| {
+        use frame_support::traits::tokens::fungibles::{Inspect, Mutate};
+        use frame_support::traits::tokens::{Fortitude, Precision, Preservation};
+        use sp_runtime::{ArithmeticError, TokenError};
+
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
+
+        handler_set_admin_list(vec![(1, AdminRole::Creator)]);
+        let art_name = NameOf::<Test>::create_on_vec(b"testName1".to_vec());
+        let art_url = UriOf::<Test>::create_on_vec(b"https://abc1.json".to_vec());
+        let _ = handler_create_art_collection(0, art_name.clone(), art_url.clone());
+        assert_ok!(EternalArtsModule::set_collection_limit(RuntimeOrigin::signed(1), 0, Some(5)));
+
+        let (b_ids, _) = handler_mint_param();
+        assert!(<EternalArtsModule as Inspect<BidOf<Test>>>::asset_exists(0));
+        assert!(!<EternalArtsModule as Inspect<BidOf<Test>>>::asset_exists(1));
+
+        // The supply limit of the collection applies to minting.
+        assert_ok!(<EternalArtsModule as Mutate<BidOf<Test>>>::mint_into(0, &b_ids[0], 4));
+        assert_noop!(<EternalArtsModule as Mutate<BidOf<Test>>>::mint_into(0, &b_ids[0], 2), ArithmeticError::Overflow);
+        assert_noop!(<EternalArtsModule as Mutate<BidOf<Test>>>::mint_into(1, &b_ids[0], 1), TokenError::UnknownAsset);
+        assert_eq!(<EternalArtsModule as Inspect<BidOf<Test>>>::balance(0, &b_ids[0]), 4);
+        assert_eq!(<EternalArtsModule as Inspect<BidOf<Test>>>::total_issuance(0), 4);
+
+        assert_ok!(<EternalArtsModule as Mutate<BidOf<Test>>>::transfer(0, &b_ids[0], &b_ids[1], 3, Preservation::Expendable));
+        System::assert_last_event(Event::TransferArtOwner {
+            s_id: 0,
+            from_bid: b_ids[0].clone(),
+            to_bid: b_ids[1].clone(),
+            count: 3,
+        }.into());
+        assert_eq!(NftBindInfos::<Test>::get(b_ids[0].clone(), 0), 1);
+        assert_eq!(EternalArtsModule::holders_of(&0).len(), 2);
+
+        // Burning the whole balance removes the holding.
+        assert_ok!(<EternalArtsModule as Mutate<BidOf<Test>>>::burn_from(0, &b_ids[0], 1, Precision::Exact, Fortitude::Polite));
+        assert!(!NftHolders::<Test>::contains_key(0, b_ids[0].clone()));
+        assert_eq!(NftCount::<Test>::get(0), 3);
+
+        // Locked collections can not move and soulbound units can not be transferred.
+        assert_ok!(EternalArtsModule::set_collection_status(RuntimeOrigin::signed(1), 0, true));
+        assert_eq!(<EternalArtsModule as Inspect<BidOf<Test>>>::reducible_balance(0, &b_ids[1], Preservation::Expendable, Fortitude::Polite), 0);
+        assert_noop!(
+            <EternalArtsModule as Mutate<BidOf<Test>>>::transfer(0, &b_ids[1], &b_ids[0], 1, Preservation::Expendable),
+            TokenError::Frozen
+        );
+        assert_ok!(EternalArtsModule::set_collection_status(RuntimeOrigin::signed(1), 0, false));
+        ArtStatus::<Test>::mutate(0, |art_status| art_status.art_type = ArtType::Soulbound);
+        assert_noop!(
+            <EternalArtsModule as Mutate<BidOf<Test>>>::transfer(0, &b_ids[1], &b_ids[0], 1, Preservation::Expendable),
+            TokenError::Blocked
+        );
+        assert_ok!(<EternalArtsModule as Mutate<BidOf<Test>>>::mint_into(0, &b_ids[0], 1));
+    });
+}
+
+#[test]
+fn it_works_for_collection_attributes() {
+    new_test_ext().execute_with(|| {
+        use frame_support::traits::{Currency, ReservableCurrency};
+
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
+        Balances::make_free_balance_be(&1, 100);
+        Balances::make_free_balance_be(&2, 100);
+
+        handler_set_admin_list(vec![(1, AdminRole::Creator)]);
+        let art_name = NameOf::<Test>::create_on_vec(b"testName1".to_vec());
+        let art_url = UriOf::<Test>::create_on_vec(b"https://abc1.json".to_vec());
+        let _ = handler_create_art_collection(0, art_name.clone(), art_url.clone());
+
+        let artist = AttributeKeyOf::<Test>::create_on_vec(b"artist".to_vec());
+        let year = AttributeKeyOf::<Test>::create_on_vec(b"year".to_vec());
+        let value = AttributeValueOf::<Test>::create_on_vec(b"Hokusai".to_vec());
+
+        // Only editors of an existing collection can set attributes.
+        assert_noop!(
+            EternalArtsModule::set_attribute(RuntimeOrigin::signed(2), 0, artist.clone(), value.clone()),
+            Error::<Test>::NotAdministrator
+        );
+        assert_noop!(
+            EternalArtsModule::set_attribute(RuntimeOrigin::signed(1), 1, artist.clone(), value.clone()),
+            Error::<Test>::ArtCollectionNotFound
+        );
+
+        // One unit of deposit per byte of key and value.
+        assert_ok!(EternalArtsModule::set_attribute(RuntimeOrigin::signed(1), 0, artist.clone(), value.clone()));
+        System::assert_last_event(Event::AttributeSet { s_id: 0, key: artist.clone(), value: value.clone(), deposit: 13 }.into());
+        assert_eq!(Balances::reserved_balance(&1), 13);
+        assert_eq!(AttributeCount::<Test>::get(0), 1);
+
+        // Replacing a value moves the deposit to the new editor.
+        assert_ok!(EternalArtsModule::grant_collection_role(RuntimeOrigin::signed(1), 0, 2, AdminRole::MetadataEditor));
+        let value = AttributeValueOf::<Test>::create_on_vec(b"Katsushika Hokusai".to_vec());
+        assert_ok!(EternalArtsModule::set_attribute(RuntimeOrigin::signed(2), 0, artist.clone(), value.clone()));
+        assert_eq!(Balances::reserved_balance(&1), 0);
+        assert_eq!(Balances::reserved_balance(&2), 24);
+        assert_eq!(AttributeCount::<Test>::get(0), 1);
+        assert_eq!(CollectionAttributes::<Test>::get(0, artist.clone()).map(|attribute| attribute.value), Some(value));
+
+        assert_ok!(EternalArtsModule::set_attribute(RuntimeOrigin::signed(1), 0, year.clone(), AttributeValueOf::<Test>::create_on_vec(b"1831".to_vec())));
+        assert_noop!(
+            EternalArtsModule::set_attribute(RuntimeOrigin::signed(1), 0, AttributeKeyOf::<Test>::create_on_vec(b"medium".to_vec()), value.clone()),
+            Error::<Test>::TooManyAttributes
+        );
+        Balances::make_free_balance_be(&2, 10);
+        assert_noop!(
+            EternalArtsModule::set_attribute(RuntimeOrigin::signed(2), 0, year.clone(), value.clone()),
+            pallet_balances::Error::<Test>::InsufficientBalance
+        );
+
+        // Clearing returns the deposit.
+        assert_ok!(EternalArtsModule::clear_attribute(RuntimeOrigin::signed(1), 0, artist.clone()));
+        System::assert_last_event(Event::AttributeCleared { s_id: 0, key: artist.clone() }.into());
+        assert_eq!(Balances::reserved_balance(&2), 0);
+        assert_eq!(AttributeCount::<Test>::get(0), 1);
+        assert_noop!(
+            EternalArtsModule::clear_attribute(RuntimeOrigin::signed(1), 0, artist.clone()),
+            Error::<Test>::AttributeNotFound
+        );
+        assert_ok!(EternalArtsModule::clear_attribute(RuntimeOrigin::signed(1), 0, year.clone()));
+        assert!(!AttributeCount::<Test>::contains_key(0));
+        assert_eq!(Balances::reserved_balance(&1), 0);
+    });
+}
+
+#[test]
+fn it_works_for_collection_uri_validation() {
+    new_test_ext().execute_with(|| {
+        handler_set_admin_list(vec![(1, AdminRole::Creator)]);
+        let art_name = NameOf::<Test>::create_on_vec(b"testName1".to_vec());
+        let create = |uri: &[u8]| EternalArtsModule::create_art_collection(
+            RuntimeOrigin::signed(1),
+            art_name.clone(),
+            UriOf::<Test>::create_on_vec(uri.to_vec()),
+        );
+
+        assert_noop!(create(b"http://abc1.json"), Error::<Test>::UriSchemeNotAllowed);
+        assert_noop!(create(b"https://abc 1.json"), Error::<Test>::UriInvalidCharacter);
+        assert_noop!(create(b"ar://"), Error::<Test>::UriMissingTarget);
+        assert_noop!(create(b"ipfs://fbafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi"), Error::<Test>::CidUnsupportedMultibase);
+        assert_noop!(create(b"ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbz0i"), Error::<Test>::CidInvalidEncoding);
+        assert_noop!(create(b"ipfs://QmbWqxBEKC3P8tqsKc98xmWNzrzDtRLMiMPL8wBuTGsMn0"), Error::<Test>::CidInvalidEncoding);
+        // The last byte of the digest is missing.
+        assert_noop!(create(b"ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbz"), Error::<Test>::CidInvalidMultihash);
+        // A CIDv2 with the same codec and multihash.
+        assert_noop!(create(b"ipfs://bajybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi"), Error::<Test>::CidUnsupportedVersion);
+
+        // CIDv0 and CIDv1 of the same content share their multihash.
+        assert_ok!(create(b"ipfs://QmbWqxBEKC3P8tqsKc98xmWNzrzDtRLMiMPL8wBuTGsMnR/1.json"));
+        assert_eq!(ArtCollection::<Test>::get(0).and_then(|data| data.multihash), Some(handler_sha2_256_multihash()));
+        assert_ok!(create(b"ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi"));
+        assert_eq!(ArtCollection::<Test>::get(1).and_then(|data| data.multihash), Some(handler_sha2_256_multihash()));
+        assert_ok!(create(b"ipfs://zdj7Wic6KcJAfWz1c9o4M6kq9Lwd5BfbxkVafnrojaaGiSFxM"));
+        assert_eq!(ArtCollection::<Test>::get(2).and_then(|data| data.multihash), Some(handler_sha2_256_multihash()));
+
+        // Updating the uri replaces the multihash.
+        assert_ok!(EternalArtsModule::update_art_collection(
+            RuntimeOrigin::signed(1),
+            0,
+            art_name.clone(),
+            UriOf::<Test>::create_on_vec(b"ar://abc1".to_vec()),
+        ));
+        assert_eq!(ArtCollection::<Test>::get(0).and_then(|data| data.multihash), None);
+        assert_noop!(
+            EternalArtsModule::update_art_collection(
+                RuntimeOrigin::signed(1),
+                0,
+                art_name.clone(),
+                UriOf::<Test>::create_on_vec(b"ipfs://Qm".to_vec()),
+            ),
+            Error::<Test>::CidUnsupportedMultibase
+        );
+    });
+}
+
+#[test]
+fn it_works_for_content_hashes() {
+    new_test_ext().execute_with(|| {
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
+
+        handler_set_admin_list(vec![(1, AdminRole::Creator)]);
+        let art_name = NameOf::<Test>::create_on_vec(b"testName1".to_vec());
+        let art_url = UriOf::<Test>::create_on_vec(b"https://abc1.json".to_vec());
+        let _ = handler_create_art_collection(0, art_name.clone(), art_url.clone());
+        let _ = handler_create_art_collection(1, art_name.clone(), art_url.clone());
+        let content_hash = sp_io::hashing::blake2_256(b"artwork file");
+
+        assert_noop!(
+            EternalArtsModule::register_content_hash(RuntimeOrigin::signed(2), 0, content_hash),
+            Error::<Test>::NotAdministrator
+        );
+        assert_noop!(
+            EternalArtsModule::register_content_hash(RuntimeOrigin::signed(1), 2, content_hash),
+            Error::<Test>::ArtCollectionNotFound
+        );
+
+        assert_ok!(EternalArtsModule::register_content_hash(RuntimeOrigin::signed(1), 0, content_hash));
+        System::assert_last_event(Event::ContentHashRegistered { s_id: 0, content_hash }.into());
+        assert_eq!(ArtCollection::<Test>::get(0).and_then(|data| data.content_hash), Some(content_hash));
+        assert_eq!(EternalArtsModule::collection_of_content_hash(&content_hash), Some(0));
+        assert!(EternalArtsModule::verify_content_hash(&0, &content_hash));
+        assert!(!EternalArtsModule::verify_content_hash(&1, &content_hash));
+        assert!(!EternalArtsModule::verify_content_hash(&0, &sp_io::hashing::blake2_256(b"another file")));
+
+        // The same artwork can not be registered twice, and a commitment can not be replaced.
+        assert_noop!(
+            EternalArtsModule::register_content_hash(RuntimeOrigin::signed(1), 1, content_hash),
+            Error::<Test>::DuplicateContentHash
+        );
+        assert_noop!(
+            EternalArtsModule::register_content_hash(RuntimeOrigin::signed(1), 0, sp_io::hashing::blake2_256(b"another file")),
+            Error::<Test>::ContentHashAlreadySet
+        );
+
+        // Updating the collection keeps its commitment.
+        assert_ok!(EternalArtsModule::update_art_collection(
+            RuntimeOrigin::signed(1),
+            0,
+            art_name.clone(),
+            UriOf::<Test>::create_on_vec(b"https://abc2.json".to_vec()),
+        ));
+        assert_eq!(ArtCollection::<Test>::get(0).and_then(|data| data.content_hash), Some(content_hash));
+    });
+}
+
+#[test]
+fn it_works_for_genesis_config() {
+    let b_id = b"releation-id-A".to_vec();
+    handler_genesis_ext(crate::GenesisConfig::<Test> {
+        administrators: vec![(1, vec![AdminRole::Creator, AdminRole::Minter]), (2, vec![AdminRole::Locker])],
+        collections: vec![(0, 1, b"testName1".to_vec(), b"https://abc1.json".to_vec()), (3, 2, b"testName2".to_vec(), b"https://abc2.json".to_vec())],
+        statuses: vec![(3, Some(5), ArtType::LimitedEdition, true)],
+        holdings: vec![(b_id.clone(), 0, 2), (b_id.clone(), 3, 5)],
+        ..Default::default()
+    }).execute_with(|| {
+        assert!(Administrators::<Test>::get(1).contains(&AdminRole::Minter));
+        assert!(Administrators::<Test>::get(2).contains(&AdminRole::Locker));
+        assert_eq!(Administrators::<Test>::count(), 2);
+
+        assert_eq!(CollectionOwner::<Test>::get(3), Some(2));
+        assert_eq!(NextCollectionId::<Test>::get(), 4);
+        assert_eq!(ArtStatus::<Test>::get(3), StructArtStatus { limit: Some(5), art_type: ArtType::LimitedEdition, locked: true });
+
+        let b_id = BidOf::<Test>::create_on_vec(b_id);
+        assert_eq!(NftBindInfos::<Test>::get(b_id.clone(), 3), 5);
+        assert_eq!(NftCount::<Test>::get(0), 2);
+        assert_eq!(EternalArtsModule::holders_of(&3), vec![(b_id, 5)]);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Invalid genesis holding of collection 0")]
+fn correct_error_for_genesis_config_holding_over_limit() {
+    handler_genesis_ext(crate::GenesisConfig::<Test> {
+        collections: vec![(0, 1, b"testName1".to_vec(), b"https://abc1.json".to_vec())],
+        statuses: vec![(0, None, ArtType::Unique, false)],
+        holdings: vec![(b"releation-id-A".to_vec(), 0, 2)],
+        ..Default::default()
+    });
+}
+
+#[test]
+#[should_panic(expected = "Genesis status of unknown collection 1")]
+fn correct_error_for_genesis_config_unknown_collection() {
+    handler_genesis_ext(crate::GenesisConfig::<Test> {
+        collections: vec![(0, 1, b"testName1".to_vec(), b"https://abc1.json".to_vec())],
+        statuses: vec![(1, None, ArtType::OpenEdition, true)],
+        ..Default::default()
+    });
+}
+
+#[test]
+fn it_works_for_migrate_to_v1() {
+    new_test_ext().execute_with(|| {
+        use crate::migrations::v1::{MigrateToV1, OldStructArtStatus};
+        use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+        use codec::Encode;
+
+        StorageVersion::new(0).put::<EternalArtsModule>();
+        frame_support::storage::unhashed::put_raw(
+            &ArtStatus::<Test>::hashed_key_for(0),
+            &OldStructArtStatus { limit: 0, art_type: ArtType::OpenEdition, locked: true }.encode(),
+        );
+        frame_support::storage::unhashed::put_raw(
+            &ArtStatus::<Test>::hashed_key_for(1),
+            &OldStructArtStatus { limit: 7, art_type: ArtType::OpenEdition, locked: false }.encode(),
+        );
+
+        MigrateToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(EternalArtsModule::on_chain_storage_version(), 1);
+        assert_eq!(ArtStatus::<Test>::get(0).limit, None);
+        assert!(ArtStatus::<Test>::get(0).locked);
+        assert_eq!(ArtStatus::<Test>::get(1).limit, Some(7));
+    });
+}
+
+
+#[test]
+fn it_works_for_migrate_to_v2() {
+    new_test_ext().execute_with(|| {
+        use crate::migrations::v2::{AdministratorList, MigrateToV2};
+        use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+        StorageVersion::new(1).put::<EternalArtsModule>();
+        AdministratorList::<Test, ()>::put(vec![(1, 0u8), (1, 1u8), (2, 1u8), (3, 9u8)]);
+
+        MigrateToV2::<Test>::on_runtime_upgrade();
+
+        assert_eq!(EternalArtsModule::on_chain_storage_version(), 2);
+        assert!(!AdministratorList::<Test, ()>::exists());
+        assert!(Administrators::<Test>::get(1).contains(&AdminRole::Creator));
+        assert!(Administrators::<Test>::get(1).contains(&AdminRole::Minter));
+        assert_eq!(Administrators::<Test>::get(2), AdminRoles(AdminRole::Minter.flag()));
+        assert!(!Administrators::<Test>::contains_key(3));
+    });
+}
+
+#[test]
+fn it_works_for_migrate_to_v3() {
+    new_test_ext().execute_with(|| {
+        use crate::migrations::v3::{NftBindInfos as OldNftBindInfos, MigrateToV3};
+        use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+        let b_id_a = BidOf::<Test>::create_on_vec(b"releation-id-A".to_vec());
+        let b_id_b = BidOf::<Test>::create_on_vec(b"releation-id-B".to_vec());
+
+        StorageVersion::new(2).put::<EternalArtsModule>();
+        OldNftBindInfos::<Test, ()>::insert((b_id_a.clone(), 0), 1);
+        OldNftBindInfos::<Test, ()>::insert((b_id_b.clone(), 0), 2);
+        OldNftBindInfos::<Test, ()>::insert((b_id_a.clone(), 1), 5);
+        OldNftBindInfos::<Test, ()>::insert((b_id_b.clone(), 1), 0);
+
+        MigrateToV3::<Test>::on_runtime_upgrade();
+
+        assert_eq!(EternalArtsModule::on_chain_storage_version(), 3);
+        assert!(!OldNftBindInfos::<Test, ()>::contains_key((b_id_a.clone(), 0)));
+        assert_eq!(NftBindInfos::<Test>::get(b_id_a.clone(), 0), 1);
+        assert_eq!(NftBindInfos::<Test>::get(b_id_b.clone(), 0), 2);
+        assert_eq!(NftBindInfos::<Test>::get(b_id_a.clone(), 1), 5);
+        assert!(!NftBindInfos::<Test>::contains_key(b_id_b.clone(), 1));
+
+        let mut holders = EternalArtsModule::holders_of(&0);
+        holders.sort();
+        assert_eq!(holders, vec![(b_id_a.clone(), 1), (b_id_b.clone(), 2)]);
+        assert_eq!(EternalArtsModule::holders_of(&1), vec![(b_id_a.clone(), 5)]);
+    });
+}
+
+#[test]
+fn it_works_for_migrate_to_v4() {
+    new_test_ext().execute_with(|| {
+        use crate::migrations::v4::MigrateToV4;
+        use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+        // Entries written by the plain map, without a counter.
+        StorageVersion::new(3).put::<EternalArtsModule>();
+        frame_support::storage::unhashed::put(&Administrators::<Test>::hashed_key_for(1), &AdminRoles(AdminRole::Creator.flag()));
+        frame_support::storage::unhashed::put(&Administrators::<Test>::hashed_key_for(2), &AdminRoles(AdminRole::Minter.flag()));
+        assert_eq!(Administrators::<Test>::count(), 0);
+
+        MigrateToV4::<Test>::on_runtime_upgrade();
+
+        assert_eq!(EternalArtsModule::on_chain_storage_version(), 4);
+        assert_eq!(Administrators::<Test>::count(), 2);
+        assert!(Administrators::<Test>::get(1).contains(&AdminRole::Creator));
+    });
+}
+
+#[test]
+fn it_works_for_migrate_to_v5() {
+    new_test_ext().execute_with(|| {
+        use crate::migrations::v5::{ArtCollection as V5ArtCollection, MigrateToV5, OldArtCollection, OldStructArtCollectionData, OverLongEntries};
+        use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+        StorageVersion::new(4).put::<EternalArtsModule>();
+        OldArtCollection::<Test, ()>::insert(0, OldStructArtCollectionData { name: b"testName1".to_vec(), uri: b"https://abc1.json".to_vec() });
+        OldArtCollection::<Test, ()>::insert(1, OldStructArtCollectionData { name: vec![b'n'; 120], uri: vec![b'u'; 101] });
+
+        // Names and uris beyond the new bounds are never cut, the upgrade fails instead.
+        assert_eq!(OverLongEntries::count::<Test, ()>().0, OverLongEntries { names: 1, uris: 1, bids: 0 });
+        assert!(std::panic::catch_unwind(|| MigrateToV5::<Test>::on_runtime_upgrade()).is_err());
+        assert_eq!(EternalArtsModule::on_chain_storage_version(), 4);
+        assert_eq!(OldArtCollection::<Test, ()>::get(1).map(|data| (data.name.len(), data.uri.len())), Some((120, 101)));
+
+        OldArtCollection::<Test, ()>::insert(1, OldStructArtCollectionData { name: vec![b'n'; 100], uri: b"https://abc2.json".to_vec() });
+        assert!(OverLongEntries::count::<Test, ()>().0.is_empty());
+        MigrateToV5::<Test>::on_runtime_upgrade();
+
+        assert_eq!(EternalArtsModule::on_chain_storage_version(), 5);
+        let data = V5ArtCollection::<Test, ()>::get(0).unwrap();
+        assert_eq!(data.name, NameOf::<Test>::create_on_vec(b"testName1".to_vec()));
+        assert_eq!(data.uri, UriOf::<Test>::create_on_vec(b"https://abc1.json".to_vec()));
+        assert_eq!(V5ArtCollection::<Test, ()>::get(1).map(|data| data.name.len()), Some(100));
+    });
+}
+
+#[test]
+fn it_works_for_all_migrations_from_v4() {
+    new_test_ext().execute_with(|| {
+        use crate::migrations::v5::{MigrateToV5, OldArtCollection, OldStructArtCollectionData};
+        use crate::migrations::{v6::MigrateToV6, v7::MigrateToV7, v8::MigrateToV8};
+        use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+        type Migrations = (MigrateToV5<Test>, MigrateToV6<Test>, MigrateToV7<Test>, MigrateToV8<Test>);
+
+        StorageVersion::new(4).put::<EternalArtsModule>();
+        OldArtCollection::<Test, ()>::insert(0, OldStructArtCollectionData { name: b"testName1".to_vec(), uri: b"https://abc1.json".to_vec() });
+        OldArtCollection::<Test, ()>::insert(1, OldStructArtCollectionData { name: vec![b'n'; 120], uri: b"https://abc2.json".to_vec() });
+
+        // An over-long entry stops the whole upgrade before anything is written.
+        assert!(std::panic::catch_unwind(|| Migrations::on_runtime_upgrade()).is_err());
+        assert_eq!(EternalArtsModule::on_chain_storage_version(), 4);
+        assert_eq!(OldArtCollection::<Test, ()>::get(1).map(|data| data.name.len()), Some(120));
+        assert_eq!(NextCollectionId::<Test>::get(), 0);
+
+        // Once the entry is shortened every migration runs, ending on the current layout.
+        OldArtCollection::<Test, ()>::insert(1, OldStructArtCollectionData { name: b"testName2".to_vec(), uri: b"https://abc2.json".to_vec() });
+        Migrations::on_runtime_upgrade();
+
+        assert_eq!(EternalArtsModule::on_chain_storage_version(), 8);
+        assert_eq!(ArtCollection::<Test>::get(1), Some(StructArtCollectionData {
+            name: NameOf::<Test>::create_on_vec(b"testName2".to_vec()),
+            uri: UriOf::<Test>::create_on_vec(b"https://abc2.json".to_vec()),
+            multihash: None,
+            content_hash: None,
+        }));
+        assert_eq!(NextCollectionId::<Test>::get(), 2);
+    });
+}
+
+#[test]
+fn it_works_for_migrate_to_v6() {
+    new_test_ext().execute_with(|| {
+        use crate::migrations::v5::{ArtCollection as V5ArtCollection, StructArtCollectionData as V5StructArtCollectionData};
+        use crate::migrations::v6::{ArtCollection as V6ArtCollection, MigrateToV6};
+        use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+        StorageVersion::new(5).put::<EternalArtsModule>();
+        V5ArtCollection::<Test, ()>::insert(0, V5StructArtCollectionData {
+            name: NameOf::<Test>::create_on_vec(b"testName1".to_vec()),
+            uri: UriOf::<Test>::create_on_vec(b"ipfs://QmbWqxBEKC3P8tqsKc98xmWNzrzDtRLMiMPL8wBuTGsMnR".to_vec()),
+        });
+        // Uris of schemes that are no longer allowed are kept.
+        V5ArtCollection::<Test, ()>::insert(1, V5StructArtCollectionData {
+            name: NameOf::<Test>::create_on_vec(b"testName2".to_vec()),
+            uri: UriOf::<Test>::create_on_vec(b"http://abc2.json".to_vec()),
+        });
+
+        MigrateToV6::<Test>::on_runtime_upgrade();
+
+        assert_eq!(EternalArtsModule::on_chain_storage_version(), 6);
+        assert_eq!(V6ArtCollection::<Test, ()>::get(0).and_then(|data| data.multihash), Some(handler_sha2_256_multihash()));
+        assert_eq!(
+            V6ArtCollection::<Test, ()>::get(1).map(|data| (data.uri.into_inner(), data.multihash)),
+            Some((b"http://abc2.json".to_vec(), None))
+        );
+    });
+}
+
+#[test]
+fn it_works_for_migrate_to_v7() {
+    new_test_ext().execute_with(|| {
+        use crate::migrations::v6::{ArtCollection as V6ArtCollection, StructArtCollectionData as V6StructArtCollectionData};
+        use crate::migrations::v7::MigrateToV7;
+        use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+        StorageVersion::new(6).put::<EternalArtsModule>();
+        V6ArtCollection::<Test, ()>::insert(0, V6StructArtCollectionData {
+            name: NameOf::<Test>::create_on_vec(b"testName1".to_vec()),
+            uri: UriOf::<Test>::create_on_vec(b"ipfs://QmbWqxBEKC3P8tqsKc98xmWNzrzDtRLMiMPL8wBuTGsMnR".to_vec()),
+            multihash: Some(handler_sha2_256_multihash()),
+        });
+
+        MigrateToV7::<Test>::on_runtime_upgrade();
+
+        assert_eq!(EternalArtsModule::on_chain_storage_version(), 7);
+        assert_eq!(ArtCollection::<Test>::get(0), Some(StructArtCollectionData {
+            name: NameOf::<Test>::create_on_vec(b"testName1".to_vec()),
+            uri: UriOf::<Test>::create_on_vec(b"ipfs://QmbWqxBEKC3P8tqsKc98xmWNzrzDtRLMiMPL8wBuTGsMnR".to_vec()),
+            multihash: Some(handler_sha2_256_multihash()),
+            content_hash: None,
+        }));
+    });
+}
+
+#[test]
+fn it_works_for_migrate_to_v8() {
+    new_test_ext().execute_with(|| {
+        use crate::migrations::v8::MigrateToV8;
+        use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+        // Collections created with ids picked by their callers, before ids were allocated.
+        StorageVersion::new(7).put::<EternalArtsModule>();
+        for s_id in 0..2 {
+            ArtCollection::<Test>::insert(s_id, StructArtCollectionData {
+                name: NameOf::<Test>::create_on_vec(b"testName1".to_vec()),
+                uri: UriOf::<Test>::create_on_vec(b"https://abc1.json".to_vec()),
+                multihash: None,
+                content_hash: None,
+            });
+        }
+        assert_eq!(NextCollectionId::<Test>::get(), 0);
+
+        MigrateToV8::<Test>::on_runtime_upgrade();
+
+        assert_eq!(EternalArtsModule::on_chain_storage_version(), 8);
+        assert_eq!(NextCollectionId::<Test>::get(), 2);
+        handler_set_admin_list(vec![(1, AdminRole::Creator)]);
+        assert_ok!(EternalArtsModule::create_art_collection(
+            RuntimeOrigin::signed(1),
+            NameOf::<Test>::create_on_vec(b"testName3".to_vec()),
+            UriOf::<Test>::create_on_vec(b"https://abc3.json".to_vec())
+        ));
+        assert!(CollectionOwner::<Test>::contains_key(2));
+    });
+}
+
+#[test]
+fn it_works_for_migrate_to_v8_on_a_second_instance() {
+    new_test_ext().execute_with(|| {
+        use crate::migrations::v8::MigrateToV8;
+        use frame_support::instances::Instance1;
+        use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+        StorageVersion::new(7).put::<EternalArtsModule>();
+        StorageVersion::new(7).put::<EternalArtsMuseum>();
+        ArtCollection::<Test, Instance1>::insert(4, StructArtCollectionData {
+            name: NameOf::<Test, Instance1>::create_on_vec(b"testName1".to_vec()),
+            uri: UriOf::<Test, Instance1>::create_on_vec(b"https://abc1.json".to_vec()),
+            multihash: None,
+            content_hash: None,
+        });
+
+        MigrateToV8::<Test, Instance1>::on_runtime_upgrade();
+
+        assert_eq!(EternalArtsMuseum::on_chain_storage_version(), 8);
+        assert_eq!(NextCollectionId::<Test, Instance1>::get(), 5);
+        // The default instance has its own storage version and is left untouched.
+        assert_eq!(EternalArtsModule::on_chain_storage_version(), 7);
+        assert_eq!(NextCollectionId::<Test>::get(), 0);
+    });
+}
+
+fn handler_genesis_ext(eternal_arts_module: crate::GenesisConfig<Test>) -> sp_io::TestExternalities {
+    use sp_runtime::BuildStorage;
+    RuntimeGenesisConfig {
+        system: Default::default(),
+        balances: Default::default(),
+        eternal_arts_module,
+        eternal_arts_museum: Default::default(),
+    }
+        .build_storage()
+        .unwrap()
+        .into()
+}
+
+fn handler_sha2_256_multihash() -> Multihash {
+    let digest = vec![
+        195, 196, 115, 62, 200, 175, 253, 6, 207, 158, 159, 245, 15, 252, 107, 205,
+        46, 200, 90, 97, 112, 0, 75, 183, 9, 102, 156, 49, 222, 148, 57, 26,
+    ];
+    Multihash { code: 0x12, digest: BoundedVec::truncate_from(digest) }
+}
+
+fn handler_set_admin_list(administrators: Vec<(u64, AdminRole)>) {
+    assert_ok!(EternalArtsModule::update_administrator_list(RuntimeOrigin::root(), BoundedVec::create_on_vec(administrators)));
+}
+
+fn handler_create_art_collection(s_id: u64, art_name: NameOf<Test>, art_url: UriOf<Test>) -> Result<(), &'static str> {
+    assert_ok!(EternalArtsModule::create_art_collection(RuntimeOrigin::signed(1), art_name.clone(), art_url.clone()));
+
+    // Check data, from ArtCollection
+    assert_eq!(ArtCollection::<Test>::get(s_id), Some(StructArtCollectionData {
+        name: art_name.clone(),
+        uri: art_url.clone(),
+        multihash: None,
+        content_hash: None,
+    }));
+
+    return Ok(());
+}
+
+fn handler_mint_items(items: Vec<(BidOf<Test>, TypeSid, TypeCount)>) -> MintItemsOf<Test> {
+    MintItemsOf::<Test>::create_on_vec(
+        items.into_iter().map(|(b_id, s_id, count)| MintItem { b_id, s_id, count }).collect()
+    )
 }
 
-fn handler_mint_param() -> (TypeBidList, TypeSidList, TypeCountList) {
+fn handler_mint_param() -> (Vec<BidOf<Test>>, MintItemsOf<Test>) {
     let b_ids = vec![
-        TypeBid::create_on_vec(b"releation-id-A".to_vec()),
-        TypeBid::create_on_vec(b"releation-id-B".to_vec()),
-        TypeBid::create_on_vec(b"releation-id-C".to_vec()),
+        BidOf::<Test>::create_on_vec(b"releation-id-A".to_vec()),
+        BidOf::<Test>::create_on_vec(b"releation-id-B".to_vec()),
+        BidOf::<Test>::create_on_vec(b"releation-id-C".to_vec()),
     ];
-    let s_ids: TypeSidList = vec![0, 0, 1] ;// TypeSidList::<Test>::create_on_vec(vec![0, 0, 1]);
-    let count_list: TypeCountList = vec![1, 2, 5];
-    (b_ids, s_ids, count_list)
+    let items = handler_mint_items(vec![
+        (b_ids[0].clone(), 0, 1),
+        (b_ids[1].clone(), 0, 2),
+        (b_ids[2].clone(), 1, 5),
+    ]);
+    (b_ids, items)
 }
\ No newline at end of file
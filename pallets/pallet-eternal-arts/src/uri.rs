@@ -0,0 +1,143 @@
+//! Validation of collection uris and decoding of the CIDs of `ipfs://` uris.
+use super::*;
+use frame_support::RuntimeDebug;
+
+/// The scheme of content-addressed uris, its target starts with a CID.
+pub const IPFS_SCHEME: &[u8] = b"ipfs://";
+
+/// The multicodec of sha2-256, the only hash function of CIDv0.
+pub const SHA2_256: u64 = 0x12;
+
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Why a uri was rejected.
+#[derive(RuntimeDebug, Clone, Copy, PartialEq, Eq)]
+pub enum UriError {
+	/// Only printable ASCII without spaces is allowed.
+	InvalidCharacter,
+	/// The uri does not start with an allowed scheme.
+	SchemeNotAllowed,
+	/// Nothing follows the scheme.
+	MissingTarget,
+	/// The CID is neither a CIDv0 nor base32 or base58btc.
+	UnsupportedMultibase,
+	/// The CID is not valid in its multibase or its varints are truncated.
+	InvalidCidEncoding,
+	/// Only CIDv0 and CIDv1 are known.
+	UnsupportedCidVersion,
+	/// The multihash of the CID is malformed or its digest is too long.
+	InvalidMultihash,
+}
+
+/// Checks `uri` against the allowed `schemes` and returns the multihash of an `ipfs://` uri.
+pub fn validate_uri(uri: &[u8], schemes: &[&[u8]]) -> Result<Option<Multihash>, UriError> {
+	ensure!(uri.iter().all(|byte| (0x21..=0x7e).contains(byte)), UriError::InvalidCharacter);
+	let scheme = schemes.iter().find(|scheme| uri.starts_with(scheme)).ok_or(UriError::SchemeNotAllowed)?;
+	let target = &uri[scheme.len()..];
+	ensure!(!target.is_empty(), UriError::MissingTarget);
+
+	if *scheme == IPFS_SCHEME {
+		decode_ipfs_target(target).map(Some)
+	} else {
+		Ok(None)
+	}
+}
+
+/// The multihash of the CID an `ipfs://` target starts with, before any path or query.
+pub fn decode_ipfs_target(target: &[u8]) -> Result<Multihash, UriError> {
+	let end = target.iter().position(|byte| matches!(byte, b'/' | b'?' | b'#')).unwrap_or(target.len());
+	decode_cid(&target[..end])
+}
+
+/// Decodes a CIDv0, or a CIDv1 in base32 or base58btc, into its multihash.
+pub fn decode_cid(cid: &[u8]) -> Result<Multihash, UriError> {
+	ensure!(!cid.is_empty(), UriError::MissingTarget);
+	// A CIDv0 is a bare base58btc sha2-256 multihash.
+	if cid.len() == 46 && cid.starts_with(b"Qm") {
+		let multihash = decode_multihash(&decode_base58(cid)?)?;
+		ensure!(multihash.code == SHA2_256, UriError::InvalidMultihash);
+		return Ok(multihash);
+	}
+
+	let bytes = match cid[0] {
+		b'b' => decode_base32(&cid[1..])?,
+		b'z' => decode_base58(&cid[1..])?,
+		_ => return Err(UriError::UnsupportedMultibase),
+	};
+	let mut input = &bytes[..];
+	ensure!(read_varint(&mut input)? == 1, UriError::UnsupportedCidVersion);
+	// Any content codec is accepted.
+	read_varint(&mut input)?;
+	decode_multihash(input)
+}
+
+/// A `<code><length><digest>` multihash, the digest must fill the rest of `input`.
+fn decode_multihash(mut input: &[u8]) -> Result<Multihash, UriError> {
+	let code = read_varint(&mut input).map_err(|_| UriError::InvalidMultihash)?;
+	let length = read_varint(&mut input).map_err(|_| UriError::InvalidMultihash)?;
+	ensure!(length > 0 && length == input.len() as u64, UriError::InvalidMultihash);
+	let digest = BoundedVec::try_from(input.to_vec()).map_err(|_| UriError::InvalidMultihash)?;
+	Ok(Multihash { code, digest })
+}
+
+/// An unsigned LEB128 varint of at most nine bytes.
+fn read_varint(input: &mut &[u8]) -> Result<u64, UriError> {
+	let mut value = 0u64;
+	for shift in (0..63).step_by(7) {
+		let (byte, rest) = input.split_first().ok_or(UriError::InvalidCidEncoding)?;
+		*input = rest;
+		value |= ((byte & 0x7f) as u64) << shift;
+		if byte & 0x80 == 0 {
+			return Ok(value);
+		}
+	}
+	Err(UriError::InvalidCidEncoding)
+}
+
+/// Lowercase RFC 4648 base32 without padding.
+fn decode_base32(input: &[u8]) -> Result<Vec<u8>, UriError> {
+	let mut bytes = Vec::with_capacity(input.len() * 5 / 8);
+	let (mut buffer, mut bits) = (0u32, 0u32);
+	for character in input {
+		let value = match character {
+			b'a'..=b'z' => character - b'a',
+			b'2'..=b'7' => character - b'2' + 26,
+			_ => return Err(UriError::InvalidCidEncoding),
+		};
+		buffer = (buffer << 5) | value as u32;
+		bits += 5;
+		if bits >= 8 {
+			bits -= 8;
+			bytes.push((buffer >> bits) as u8);
+			buffer &= (1 << bits) - 1;
+		}
+	}
+	// The bits left over are padding and must be zero.
+	ensure!(buffer == 0, UriError::InvalidCidEncoding);
+	Ok(bytes)
+}
+
+/// Bitcoin base58, leading `1`s are leading zero bytes.
+fn decode_base58(input: &[u8]) -> Result<Vec<u8>, UriError> {
+	// Little endian while decoding.
+	let mut bytes: Vec<u8> = Vec::with_capacity(input.len());
+	for character in input {
+		let mut carry = BASE58_ALPHABET
+			.iter()
+			.position(|digit| digit == character)
+			.ok_or(UriError::InvalidCidEncoding)? as u32;
+		for byte in bytes.iter_mut() {
+			carry += *byte as u32 * 58;
+			*byte = carry as u8;
+			carry >>= 8;
+		}
+		while carry > 0 {
+			bytes.push(carry as u8);
+			carry >>= 8;
+		}
+	}
+	let zeros = input.iter().take_while(|character| **character == b'1').count();
+	bytes.extend(sp_std::iter::repeat(0).take(zeros));
+	bytes.reverse();
+	Ok(bytes)
+}
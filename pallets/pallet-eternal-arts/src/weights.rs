@@ -1,29 +1,12 @@
-
-//! Autogenerated weights for pallet_template
+//! Weights for pallet_eternal_arts.
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2023-04-06, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `Alexs-MacBook-Pro-2.local`, CPU: `<UNKNOWN>`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
-
-// Executed Command:
-// ../../target/release/node-eternal-civilization
-// benchmark
-// pallet
-// --chain
-// dev
-// --pallet
-// pallet_template
-// --extrinsic
-// *
-// --steps=50
-// --repeat=20
-// --wasm-execution=compiled
-// --output
-// pallets/template/src/weights.rs
-// --template
-// ../../.maintain/frame-weight-template.hbs
+//! PLACEHOLDER WEIGHTS, NOT BENCHMARK OUTPUT. The storage accesses are taken from the code,
+//! the execution times and proof sizes are rounded up guesses. Replace this file with the
+//! output of the benchmark CLI on reference hardware before production use:
+//!
+//! ./target/release/node-eternal-civilization benchmark pallet --chain dev --pallet pallet_eternal_arts \
+//!     --extrinsic '*' --steps 50 --repeat 20 --wasm-execution compiled \
+//!     --output pallets/pallet-eternal-arts/src/weights.rs --template ../../.maintain/frame-weight-template.hbs
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -32,59 +15,597 @@
 use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
 use core::marker::PhantomData;
 
-/// Weight functions needed for pallet_template.
+/// Weight functions needed for pallet_eternal_arts.
 pub trait WeightInfo {
-	fn do_something() -> Weight;
-	fn cause_error() -> Weight;
+	fn create_art_collection(n: u32, u: u32) -> Weight;
+	fn update_art_collection(n: u32, u: u32) -> Weight;
+	fn issue_art_ownership(b: u32) -> Weight;
+	fn update_administrator_list(c: u32, a: u32) -> Weight;
+	fn set_collection_status() -> Weight;
+	fn set_collection_limit() -> Weight;
+	fn set_collection_type() -> Weight;
+	fn transfer_art_ownership() -> Weight;
+	fn revoke_art_ownership(b: u32) -> Weight;
+	fn grant_role() -> Weight;
+	fn revoke_role() -> Weight;
+	fn grant_collection_role() -> Weight;
+	fn revoke_collection_role() -> Weight;
+	fn force_create_art_collection(n: u32, u: u32) -> Weight;
+	fn issue_art_ownership_best_effort(b: u32) -> Weight;
+	fn add_administrator() -> Weight;
+	fn remove_administrator() -> Weight;
+	fn set_administrator_expiry() -> Weight;
+	fn set_attribute(k: u32, v: u32) -> Weight;
+	fn clear_attribute() -> Weight;
+	fn register_content_hash() -> Weight;
+	fn prune_expired_administrator() -> Weight;
 }
 
-/// Weights for pallet_template using the Substrate node and recommended hardware.
+/// Weights for pallet_eternal_arts using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-	/// Storage: TemplateModule Something (r:0 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn do_something() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 8_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 0)
+	/// Storage: EternalArtsModule Administrators (r:1 w:0)
+	/// Storage: EternalArtsModule AdministratorExpiries (r:1 w:0)
+	/// Storage: EternalArtsModule ArtCollection (r:1 w:1)
+	/// Storage: EternalArtsModule NextCollectionId (r:1 w:1)
+	/// Storage: EternalArtsModule CollectionOwner (r:0 w:1)
+	/// The range of component `n` is `[1, MaxNameLength]`.
+	/// The range of component `u` is `[54, MaxUriLength]`.
+	fn create_art_collection(n: u32, u: u32) -> Weight {
+		Weight::from_parts(20_000_000, 4000)
+			.saturating_add(Weight::from_parts(3_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(4_000, 0).saturating_mul(u.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: EternalArtsModule CollectionOwner (r:1 w:0)
+	/// Storage: EternalArtsModule Administrators (r:1 w:0)
+	/// Storage: EternalArtsModule AdministratorExpiries (r:1 w:0)
+	/// Storage: EternalArtsModule CollectionAdministrators (r:1 w:0)
+	/// Storage: EternalArtsModule ArtCollection (r:1 w:1)
+	/// The range of component `n` is `[1, MaxNameLength]`.
+	/// The range of component `u` is `[54, MaxUriLength]`.
+	fn update_art_collection(n: u32, u: u32) -> Weight {
+		Weight::from_parts(30_000_000, 4000)
+			.saturating_add(Weight::from_parts(3_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(5_000, 0).saturating_mul(u.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-	/// Storage: TemplateModule Something (r:1 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn cause_error() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
+	/// Storage: EternalArtsModule ProcessedRequests (r:1 w:1)
+	/// Storage: EternalArtsModule NextRequestExpiry (r:1 w:1)
+	/// Storage: EternalArtsModule RequestExpiries (r:0 w:1)
+	/// Storage: EternalArtsModule CollectionOwner (r:1 w:0) per `b`
+	/// Storage: EternalArtsModule Administrators (r:1 w:0) per `b`
+	/// Storage: EternalArtsModule AdministratorExpiries (r:1 w:0) per `b`
+	/// Storage: EternalArtsModule CollectionAdministrators (r:1 w:0) per `b`
+	/// Storage: EternalArtsModule ArtCollection (r:1 w:0) per `b`
+	/// Storage: EternalArtsModule ArtStatus (r:1 w:0) per `b`
+	/// Storage: EternalArtsModule NftCount (r:1 w:1) per `b`
+	/// Storage: EternalArtsModule NftBindInfos (r:1 w:1) per `b`
+	/// Storage: EternalArtsModule NftHolders (r:0 w:1) per `b`
+	/// The range of component `b` is `[1, MaxBatch]`.
+	fn issue_art_ownership(b: u32) -> Weight {
+		Weight::from_parts(30_000_000, 4000)
+			.saturating_add(Weight::from_parts(20_000_000, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((8_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(b.into())))
+	}
+	/// Storage: EternalArtsModule Administrators (r:0 w:1) per `c`
+	/// Storage: EternalArtsModule AdministratorExpiries (r:0 w:1) per `c`
+	/// Storage: EternalArtsModule AdministratorsByExpiry (r:0 w:1) per `c`
+	/// Storage: EternalArtsModule Administrators (r:1 w:1) per `a`
+	/// Storage: EternalArtsModule CounterForAdministrators (r:1 w:1)
+	/// Storage: EternalArtsModule NextAdministratorExpiry (r:0 w:1)
+	/// The range of component `c` is `[0, MaxAdministrators]`.
+	/// The range of component `a` is `[1, MaxAdministrators]`.
+	fn update_administrator_list(c: u32, a: u32) -> Weight {
+		Weight::from_parts(20_000_000, 2000)
+			.saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(2_000_000, 3000).saturating_mul(a.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
+	}
+	/// Storage: EternalArtsModule ArtCollection (r:1 w:0)
+	/// Storage: EternalArtsModule CollectionOwner (r:1 w:0)
+	/// Storage: EternalArtsModule Administrators (r:1 w:0)
+	/// Storage: EternalArtsModule AdministratorExpiries (r:1 w:0)
+	/// Storage: EternalArtsModule CollectionAdministrators (r:1 w:0)
+	/// Storage: EternalArtsModule ArtStatus (r:1 w:1)
+	fn set_collection_status() -> Weight {
+		Weight::from_parts(20_000_000, 4000)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: EternalArtsModule CollectionOwner (r:1 w:0)
+	/// Storage: EternalArtsModule Administrators (r:1 w:0)
+	/// Storage: EternalArtsModule AdministratorExpiries (r:1 w:0)
+	/// Storage: EternalArtsModule ArtCollection (r:1 w:0)
+	/// Storage: EternalArtsModule NftCount (r:1 w:0)
+	/// Storage: EternalArtsModule ArtStatus (r:1 w:1)
+	fn set_collection_limit() -> Weight {
+		Weight::from_parts(20_000_000, 4000)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: EternalArtsModule CollectionOwner (r:1 w:0)
+	/// Storage: EternalArtsModule Administrators (r:1 w:0)
+	/// Storage: EternalArtsModule AdministratorExpiries (r:1 w:0)
+	/// Storage: EternalArtsModule ArtCollection (r:1 w:0)
+	/// Storage: EternalArtsModule NftCount (r:1 w:0)
+	/// Storage: EternalArtsModule ArtStatus (r:1 w:1)
+	fn set_collection_type() -> Weight {
+		Weight::from_parts(20_000_000, 4000)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: EternalArtsModule CollectionOwner (r:1 w:0)
+	/// Storage: EternalArtsModule Administrators (r:1 w:0)
+	/// Storage: EternalArtsModule AdministratorExpiries (r:1 w:0)
+	/// Storage: EternalArtsModule CollectionAdministrators (r:1 w:0)
+	/// Storage: EternalArtsModule ArtCollection (r:1 w:0)
+	/// Storage: EternalArtsModule ArtStatus (r:1 w:0)
+	/// Storage: EternalArtsModule NftBindInfos (r:2 w:2)
+	/// Storage: EternalArtsModule NftHolders (r:0 w:2)
+	fn transfer_art_ownership() -> Weight {
+		Weight::from_parts(30_000_000, 7000)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: EternalArtsModule CollectionOwner (r:1 w:0) per `b`
+	/// Storage: EternalArtsModule Administrators (r:1 w:0) per `b`
+	/// Storage: EternalArtsModule AdministratorExpiries (r:1 w:0) per `b`
+	/// Storage: EternalArtsModule CollectionAdministrators (r:1 w:0) per `b`
+	/// Storage: EternalArtsModule ArtCollection (r:1 w:0) per `b`
+	/// Storage: EternalArtsModule ArtStatus (r:1 w:0) per `b`
+	/// Storage: EternalArtsModule NftBindInfos (r:1 w:1) per `b`
+	/// Storage: EternalArtsModule NftHolders (r:0 w:1) per `b`
+	/// Storage: EternalArtsModule NftCount (r:1 w:1) per `b`
+	/// The range of component `b` is `[1, MaxBatch]`.
+	fn revoke_art_ownership(b: u32) -> Weight {
+		Weight::from_parts(20_000_000, 2000)
+			.saturating_add(Weight::from_parts(20_000_000, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads((8_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(b.into())))
+	}
+	/// Storage: EternalArtsModule Administrators (r:1 w:1)
+	/// Storage: EternalArtsModule CounterForAdministrators (r:1 w:1)
+	/// Storage: EternalArtsModule AdministratorExpiries (r:1 w:1)
+	/// Storage: EternalArtsModule AdministratorsByExpiry (r:1 w:1)
+	/// Storage: EternalArtsModule NextAdministratorExpiry (r:0 w:1)
+	fn grant_role() -> Weight {
+		Weight::from_parts(20_000_000, 4000)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: EternalArtsModule Administrators (r:1 w:1)
+	/// Storage: EternalArtsModule CounterForAdministrators (r:1 w:1)
+	/// Storage: EternalArtsModule AdministratorExpiries (r:1 w:1)
+	/// Storage: EternalArtsModule AdministratorsByExpiry (r:1 w:1)
+	/// Storage: EternalArtsModule NextAdministratorExpiry (r:0 w:1)
+	fn revoke_role() -> Weight {
+		Weight::from_parts(20_000_000, 4000)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: EternalArtsModule ArtCollection (r:1 w:0)
+	/// Storage: EternalArtsModule CollectionOwner (r:1 w:0)
+	/// Storage: EternalArtsModule Administrators (r:1 w:0)
+	/// Storage: EternalArtsModule AdministratorExpiries (r:1 w:0)
+	/// Storage: EternalArtsModule CollectionAdministrators (r:1 w:1)
+	fn grant_collection_role() -> Weight {
+		Weight::from_parts(20_000_000, 4000)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: EternalArtsModule ArtCollection (r:1 w:0)
+	/// Storage: EternalArtsModule CollectionOwner (r:1 w:0)
+	/// Storage: EternalArtsModule Administrators (r:1 w:0)
+	/// Storage: EternalArtsModule AdministratorExpiries (r:1 w:0)
+	/// Storage: EternalArtsModule CollectionAdministrators (r:1 w:1)
+	fn revoke_collection_role() -> Weight {
+		Weight::from_parts(20_000_000, 4000)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: EternalArtsModule ArtCollection (r:1 w:1)
+	/// Storage: EternalArtsModule NextCollectionId (r:1 w:1)
+	/// Storage: EternalArtsModule CollectionOwner (r:0 w:1)
+	/// The range of component `n` is `[1, MaxNameLength]`.
+	/// The range of component `u` is `[54, MaxUriLength]`.
+	fn force_create_art_collection(n: u32, u: u32) -> Weight {
+		Weight::from_parts(20_000_000, 4000)
+			.saturating_add(Weight::from_parts(3_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(4_000, 0).saturating_mul(u.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: EternalArtsModule ProcessedRequests (r:1 w:1)
+	/// Storage: EternalArtsModule NextRequestExpiry (r:1 w:1)
+	/// Storage: EternalArtsModule RequestExpiries (r:0 w:1)
+	/// Storage: EternalArtsModule CollectionOwner (r:1 w:0) per `b`
+	/// Storage: EternalArtsModule Administrators (r:1 w:0) per `b`
+	/// Storage: EternalArtsModule AdministratorExpiries (r:1 w:0) per `b`
+	/// Storage: EternalArtsModule CollectionAdministrators (r:1 w:0) per `b`
+	/// Storage: EternalArtsModule ArtCollection (r:1 w:0) per `b`
+	/// Storage: EternalArtsModule ArtStatus (r:1 w:0) per `b`
+	/// Storage: EternalArtsModule NftCount (r:1 w:1) per `b`
+	/// Storage: EternalArtsModule NftBindInfos (r:1 w:1) per `b`
+	/// Storage: EternalArtsModule NftHolders (r:0 w:1) per `b`
+	/// The range of component `b` is `[1, MaxBatch]`.
+	fn issue_art_ownership_best_effort(b: u32) -> Weight {
+		Weight::from_parts(30_000_000, 4000)
+			.saturating_add(Weight::from_parts(20_000_000, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((8_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(b.into())))
+	}
+	/// Storage: EternalArtsModule Administrators (r:1 w:1)
+	/// Storage: EternalArtsModule CounterForAdministrators (r:1 w:1)
+	/// Storage: EternalArtsModule AdministratorExpiries (r:1 w:1)
+	/// Storage: EternalArtsModule AdministratorsByExpiry (r:1 w:1)
+	/// Storage: EternalArtsModule NextAdministratorExpiry (r:0 w:1)
+	fn add_administrator() -> Weight {
+		Weight::from_parts(20_000_000, 4000)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: EternalArtsModule Administrators (r:1 w:1)
+	/// Storage: EternalArtsModule CounterForAdministrators (r:1 w:1)
+	/// Storage: EternalArtsModule AdministratorExpiries (r:1 w:1)
+	/// Storage: EternalArtsModule AdministratorsByExpiry (r:1 w:1)
+	/// Storage: EternalArtsModule NextAdministratorExpiry (r:0 w:1)
+	fn remove_administrator() -> Weight {
+		Weight::from_parts(20_000_000, 4000)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: EternalArtsModule Administrators (r:1 w:0)
+	/// Storage: EternalArtsModule AdministratorExpiries (r:1 w:1)
+	/// Storage: EternalArtsModule NextAdministratorExpiry (r:1 w:1)
+	/// Storage: EternalArtsModule AdministratorsByExpiry (r:1 w:2)
+	fn set_administrator_expiry() -> Weight {
+		Weight::from_parts(30_000_000, 4000)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: EternalArtsModule CollectionOwner (r:1 w:0)
+	/// Storage: EternalArtsModule Administrators (r:1 w:0)
+	/// Storage: EternalArtsModule AdministratorExpiries (r:1 w:0)
+	/// Storage: EternalArtsModule CollectionAdministrators (r:1 w:0)
+	/// Storage: EternalArtsModule ArtCollection (r:1 w:0)
+	/// Storage: EternalArtsModule CollectionAttributes (r:1 w:1)
+	/// Storage: EternalArtsModule AttributeCount (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// The range of component `k` is `[1, MaxAttributeKeyLength]`.
+	/// The range of component `v` is `[0, MaxAttributeValueLength]`.
+	fn set_attribute(k: u32, v: u32) -> Weight {
+		Weight::from_parts(40_000_000, 4000)
+			.saturating_add(Weight::from_parts(2_000, 0).saturating_mul(k.into()))
+			.saturating_add(Weight::from_parts(2_000, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: EternalArtsModule CollectionOwner (r:1 w:0)
+	/// Storage: EternalArtsModule Administrators (r:1 w:0)
+	/// Storage: EternalArtsModule AdministratorExpiries (r:1 w:0)
+	/// Storage: EternalArtsModule CollectionAdministrators (r:1 w:0)
+	/// Storage: EternalArtsModule CollectionAttributes (r:1 w:1)
+	/// Storage: EternalArtsModule AttributeCount (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn clear_attribute() -> Weight {
+		Weight::from_parts(30_000_000, 4000)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: EternalArtsModule CollectionOwner (r:1 w:0)
+	/// Storage: EternalArtsModule Administrators (r:1 w:0)
+	/// Storage: EternalArtsModule AdministratorExpiries (r:1 w:0)
+	/// Storage: EternalArtsModule CollectionAdministrators (r:1 w:0)
+	/// Storage: EternalArtsModule ContentHashes (r:1 w:1)
+	/// Storage: EternalArtsModule ArtCollection (r:1 w:1)
+	fn register_content_hash() -> Weight {
+		Weight::from_parts(30_000_000, 4000)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: EternalArtsModule AdministratorsByExpiry (r:1 w:1)
+	/// Storage: EternalArtsModule AdministratorExpiries (r:0 w:1)
+	/// Storage: EternalArtsModule Administrators (r:1 w:1)
+	/// Storage: EternalArtsModule CounterForAdministrators (r:1 w:1)
+	fn prune_expired_administrator() -> Weight {
+		Weight::from_parts(20_000_000, 4000)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
-	/// Storage: TemplateModule Something (r:0 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn do_something() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 8_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 0)
+	/// Storage: EternalArtsModule Administrators (r:1 w:0)
+	/// Storage: EternalArtsModule AdministratorExpiries (r:1 w:0)
+	/// Storage: EternalArtsModule ArtCollection (r:1 w:1)
+	/// Storage: EternalArtsModule NextCollectionId (r:1 w:1)
+	/// Storage: EternalArtsModule CollectionOwner (r:0 w:1)
+	/// The range of component `n` is `[1, MaxNameLength]`.
+	/// The range of component `u` is `[54, MaxUriLength]`.
+	fn create_art_collection(n: u32, u: u32) -> Weight {
+		Weight::from_parts(20_000_000, 4000)
+			.saturating_add(Weight::from_parts(3_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(4_000, 0).saturating_mul(u.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: EternalArtsModule CollectionOwner (r:1 w:0)
+	/// Storage: EternalArtsModule Administrators (r:1 w:0)
+	/// Storage: EternalArtsModule AdministratorExpiries (r:1 w:0)
+	/// Storage: EternalArtsModule CollectionAdministrators (r:1 w:0)
+	/// Storage: EternalArtsModule ArtCollection (r:1 w:1)
+	/// The range of component `n` is `[1, MaxNameLength]`.
+	/// The range of component `u` is `[54, MaxUriLength]`.
+	fn update_art_collection(n: u32, u: u32) -> Weight {
+		Weight::from_parts(30_000_000, 4000)
+			.saturating_add(Weight::from_parts(3_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(5_000, 0).saturating_mul(u.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-	/// Storage: TemplateModule Something (r:1 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn cause_error() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
+	/// Storage: EternalArtsModule ProcessedRequests (r:1 w:1)
+	/// Storage: EternalArtsModule NextRequestExpiry (r:1 w:1)
+	/// Storage: EternalArtsModule RequestExpiries (r:0 w:1)
+	/// Storage: EternalArtsModule CollectionOwner (r:1 w:0) per `b`
+	/// Storage: EternalArtsModule Administrators (r:1 w:0) per `b`
+	/// Storage: EternalArtsModule AdministratorExpiries (r:1 w:0) per `b`
+	/// Storage: EternalArtsModule CollectionAdministrators (r:1 w:0) per `b`
+	/// Storage: EternalArtsModule ArtCollection (r:1 w:0) per `b`
+	/// Storage: EternalArtsModule ArtStatus (r:1 w:0) per `b`
+	/// Storage: EternalArtsModule NftCount (r:1 w:1) per `b`
+	/// Storage: EternalArtsModule NftBindInfos (r:1 w:1) per `b`
+	/// Storage: EternalArtsModule NftHolders (r:0 w:1) per `b`
+	/// The range of component `b` is `[1, MaxBatch]`.
+	fn issue_art_ownership(b: u32) -> Weight {
+		Weight::from_parts(30_000_000, 4000)
+			.saturating_add(Weight::from_parts(20_000_000, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((8_u64).saturating_mul(b.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(b.into())))
+	}
+	/// Storage: EternalArtsModule Administrators (r:0 w:1) per `c`
+	/// Storage: EternalArtsModule AdministratorExpiries (r:0 w:1) per `c`
+	/// Storage: EternalArtsModule AdministratorsByExpiry (r:0 w:1) per `c`
+	/// Storage: EternalArtsModule Administrators (r:1 w:1) per `a`
+	/// Storage: EternalArtsModule CounterForAdministrators (r:1 w:1)
+	/// Storage: EternalArtsModule NextAdministratorExpiry (r:0 w:1)
+	/// The range of component `c` is `[0, MaxAdministrators]`.
+	/// The range of component `a` is `[1, MaxAdministrators]`.
+	fn update_administrator_list(c: u32, a: u32) -> Weight {
+		Weight::from_parts(20_000_000, 2000)
+			.saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(2_000_000, 3000).saturating_mul(a.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(a.into())))
+	}
+	/// Storage: EternalArtsModule ArtCollection (r:1 w:0)
+	/// Storage: EternalArtsModule CollectionOwner (r:1 w:0)
+	/// Storage: EternalArtsModule Administrators (r:1 w:0)
+	/// Storage: EternalArtsModule AdministratorExpiries (r:1 w:0)
+	/// Storage: EternalArtsModule CollectionAdministrators (r:1 w:0)
+	/// Storage: EternalArtsModule ArtStatus (r:1 w:1)
+	fn set_collection_status() -> Weight {
+		Weight::from_parts(20_000_000, 4000)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: EternalArtsModule CollectionOwner (r:1 w:0)
+	/// Storage: EternalArtsModule Administrators (r:1 w:0)
+	/// Storage: EternalArtsModule AdministratorExpiries (r:1 w:0)
+	/// Storage: EternalArtsModule ArtCollection (r:1 w:0)
+	/// Storage: EternalArtsModule NftCount (r:1 w:0)
+	/// Storage: EternalArtsModule ArtStatus (r:1 w:1)
+	fn set_collection_limit() -> Weight {
+		Weight::from_parts(20_000_000, 4000)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: EternalArtsModule CollectionOwner (r:1 w:0)
+	/// Storage: EternalArtsModule Administrators (r:1 w:0)
+	/// Storage: EternalArtsModule AdministratorExpiries (r:1 w:0)
+	/// Storage: EternalArtsModule ArtCollection (r:1 w:0)
+	/// Storage: EternalArtsModule NftCount (r:1 w:0)
+	/// Storage: EternalArtsModule ArtStatus (r:1 w:1)
+	fn set_collection_type() -> Weight {
+		Weight::from_parts(20_000_000, 4000)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: EternalArtsModule CollectionOwner (r:1 w:0)
+	/// Storage: EternalArtsModule Administrators (r:1 w:0)
+	/// Storage: EternalArtsModule AdministratorExpiries (r:1 w:0)
+	/// Storage: EternalArtsModule CollectionAdministrators (r:1 w:0)
+	/// Storage: EternalArtsModule ArtCollection (r:1 w:0)
+	/// Storage: EternalArtsModule ArtStatus (r:1 w:0)
+	/// Storage: EternalArtsModule NftBindInfos (r:2 w:2)
+	/// Storage: EternalArtsModule NftHolders (r:0 w:2)
+	fn transfer_art_ownership() -> Weight {
+		Weight::from_parts(30_000_000, 7000)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: EternalArtsModule CollectionOwner (r:1 w:0) per `b`
+	/// Storage: EternalArtsModule Administrators (r:1 w:0) per `b`
+	/// Storage: EternalArtsModule AdministratorExpiries (r:1 w:0) per `b`
+	/// Storage: EternalArtsModule CollectionAdministrators (r:1 w:0) per `b`
+	/// Storage: EternalArtsModule ArtCollection (r:1 w:0) per `b`
+	/// Storage: EternalArtsModule ArtStatus (r:1 w:0) per `b`
+	/// Storage: EternalArtsModule NftBindInfos (r:1 w:1) per `b`
+	/// Storage: EternalArtsModule NftHolders (r:0 w:1) per `b`
+	/// Storage: EternalArtsModule NftCount (r:1 w:1) per `b`
+	/// The range of component `b` is `[1, MaxBatch]`.
+	fn revoke_art_ownership(b: u32) -> Weight {
+		Weight::from_parts(20_000_000, 2000)
+			.saturating_add(Weight::from_parts(20_000_000, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads((8_u64).saturating_mul(b.into())))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(b.into())))
+	}
+	/// Storage: EternalArtsModule Administrators (r:1 w:1)
+	/// Storage: EternalArtsModule CounterForAdministrators (r:1 w:1)
+	/// Storage: EternalArtsModule AdministratorExpiries (r:1 w:1)
+	/// Storage: EternalArtsModule AdministratorsByExpiry (r:1 w:1)
+	/// Storage: EternalArtsModule NextAdministratorExpiry (r:0 w:1)
+	fn grant_role() -> Weight {
+		Weight::from_parts(20_000_000, 4000)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: EternalArtsModule Administrators (r:1 w:1)
+	/// Storage: EternalArtsModule CounterForAdministrators (r:1 w:1)
+	/// Storage: EternalArtsModule AdministratorExpiries (r:1 w:1)
+	/// Storage: EternalArtsModule AdministratorsByExpiry (r:1 w:1)
+	/// Storage: EternalArtsModule NextAdministratorExpiry (r:0 w:1)
+	fn revoke_role() -> Weight {
+		Weight::from_parts(20_000_000, 4000)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: EternalArtsModule ArtCollection (r:1 w:0)
+	/// Storage: EternalArtsModule CollectionOwner (r:1 w:0)
+	/// Storage: EternalArtsModule Administrators (r:1 w:0)
+	/// Storage: EternalArtsModule AdministratorExpiries (r:1 w:0)
+	/// Storage: EternalArtsModule CollectionAdministrators (r:1 w:1)
+	fn grant_collection_role() -> Weight {
+		Weight::from_parts(20_000_000, 4000)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: EternalArtsModule ArtCollection (r:1 w:0)
+	/// Storage: EternalArtsModule CollectionOwner (r:1 w:0)
+	/// Storage: EternalArtsModule Administrators (r:1 w:0)
+	/// Storage: EternalArtsModule AdministratorExpiries (r:1 w:0)
+	/// Storage: EternalArtsModule CollectionAdministrators (r:1 w:1)
+	fn revoke_collection_role() -> Weight {
+		Weight::from_parts(20_000_000, 4000)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: EternalArtsModule ArtCollection (r:1 w:1)
+	/// Storage: EternalArtsModule NextCollectionId (r:1 w:1)
+	/// Storage: EternalArtsModule CollectionOwner (r:0 w:1)
+	/// The range of component `n` is `[1, MaxNameLength]`.
+	/// The range of component `u` is `[54, MaxUriLength]`.
+	fn force_create_art_collection(n: u32, u: u32) -> Weight {
+		Weight::from_parts(20_000_000, 4000)
+			.saturating_add(Weight::from_parts(3_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(4_000, 0).saturating_mul(u.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: EternalArtsModule ProcessedRequests (r:1 w:1)
+	/// Storage: EternalArtsModule NextRequestExpiry (r:1 w:1)
+	/// Storage: EternalArtsModule RequestExpiries (r:0 w:1)
+	/// Storage: EternalArtsModule CollectionOwner (r:1 w:0) per `b`
+	/// Storage: EternalArtsModule Administrators (r:1 w:0) per `b`
+	/// Storage: EternalArtsModule AdministratorExpiries (r:1 w:0) per `b`
+	/// Storage: EternalArtsModule CollectionAdministrators (r:1 w:0) per `b`
+	/// Storage: EternalArtsModule ArtCollection (r:1 w:0) per `b`
+	/// Storage: EternalArtsModule ArtStatus (r:1 w:0) per `b`
+	/// Storage: EternalArtsModule NftCount (r:1 w:1) per `b`
+	/// Storage: EternalArtsModule NftBindInfos (r:1 w:1) per `b`
+	/// Storage: EternalArtsModule NftHolders (r:0 w:1) per `b`
+	/// The range of component `b` is `[1, MaxBatch]`.
+	fn issue_art_ownership_best_effort(b: u32) -> Weight {
+		Weight::from_parts(30_000_000, 4000)
+			.saturating_add(Weight::from_parts(20_000_000, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((8_u64).saturating_mul(b.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(b.into())))
+	}
+	/// Storage: EternalArtsModule Administrators (r:1 w:1)
+	/// Storage: EternalArtsModule CounterForAdministrators (r:1 w:1)
+	/// Storage: EternalArtsModule AdministratorExpiries (r:1 w:1)
+	/// Storage: EternalArtsModule AdministratorsByExpiry (r:1 w:1)
+	/// Storage: EternalArtsModule NextAdministratorExpiry (r:0 w:1)
+	fn add_administrator() -> Weight {
+		Weight::from_parts(20_000_000, 4000)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: EternalArtsModule Administrators (r:1 w:1)
+	/// Storage: EternalArtsModule CounterForAdministrators (r:1 w:1)
+	/// Storage: EternalArtsModule AdministratorExpiries (r:1 w:1)
+	/// Storage: EternalArtsModule AdministratorsByExpiry (r:1 w:1)
+	/// Storage: EternalArtsModule NextAdministratorExpiry (r:0 w:1)
+	fn remove_administrator() -> Weight {
+		Weight::from_parts(20_000_000, 4000)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: EternalArtsModule Administrators (r:1 w:0)
+	/// Storage: EternalArtsModule AdministratorExpiries (r:1 w:1)
+	/// Storage: EternalArtsModule NextAdministratorExpiry (r:1 w:1)
+	/// Storage: EternalArtsModule AdministratorsByExpiry (r:1 w:2)
+	fn set_administrator_expiry() -> Weight {
+		Weight::from_parts(30_000_000, 4000)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: EternalArtsModule CollectionOwner (r:1 w:0)
+	/// Storage: EternalArtsModule Administrators (r:1 w:0)
+	/// Storage: EternalArtsModule AdministratorExpiries (r:1 w:0)
+	/// Storage: EternalArtsModule CollectionAdministrators (r:1 w:0)
+	/// Storage: EternalArtsModule ArtCollection (r:1 w:0)
+	/// Storage: EternalArtsModule CollectionAttributes (r:1 w:1)
+	/// Storage: EternalArtsModule AttributeCount (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// The range of component `k` is `[1, MaxAttributeKeyLength]`.
+	/// The range of component `v` is `[0, MaxAttributeValueLength]`.
+	fn set_attribute(k: u32, v: u32) -> Weight {
+		Weight::from_parts(40_000_000, 4000)
+			.saturating_add(Weight::from_parts(2_000, 0).saturating_mul(k.into()))
+			.saturating_add(Weight::from_parts(2_000, 0).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: EternalArtsModule CollectionOwner (r:1 w:0)
+	/// Storage: EternalArtsModule Administrators (r:1 w:0)
+	/// Storage: EternalArtsModule AdministratorExpiries (r:1 w:0)
+	/// Storage: EternalArtsModule CollectionAdministrators (r:1 w:0)
+	/// Storage: EternalArtsModule CollectionAttributes (r:1 w:1)
+	/// Storage: EternalArtsModule AttributeCount (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn clear_attribute() -> Weight {
+		Weight::from_parts(30_000_000, 4000)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: EternalArtsModule CollectionOwner (r:1 w:0)
+	/// Storage: EternalArtsModule Administrators (r:1 w:0)
+	/// Storage: EternalArtsModule AdministratorExpiries (r:1 w:0)
+	/// Storage: EternalArtsModule CollectionAdministrators (r:1 w:0)
+	/// Storage: EternalArtsModule ContentHashes (r:1 w:1)
+	/// Storage: EternalArtsModule ArtCollection (r:1 w:1)
+	fn register_content_hash() -> Weight {
+		Weight::from_parts(30_000_000, 4000)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: EternalArtsModule AdministratorsByExpiry (r:1 w:1)
+	/// Storage: EternalArtsModule AdministratorExpiries (r:0 w:1)
+	/// Storage: EternalArtsModule Administrators (r:1 w:1)
+	/// Storage: EternalArtsModule CounterForAdministrators (r:1 w:1)
+	fn prune_expired_administrator() -> Weight {
+		Weight::from_parts(20_000_000, 4000)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
 }